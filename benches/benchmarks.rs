@@ -1,6 +1,8 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use openssl::rand::rand_bytes;
-use shamirss::{combine_inlined, create_inlined, errors::SSSError};
+use shamirss::{
+    combine_inlined, create_gf256, create_inlined, create_std, errors::SSSError, Dealer,
+};
 
 fn get_random_bytes(size: usize) -> Result<Vec<u8>, SSSError> {
     let mut bytes = vec![0; size];
@@ -63,9 +65,121 @@ fn benchmark_combine_all_inlined_min_50_shares_100_secret_512(c: &mut Criterion)
     }
 }
 
+fn benchmark_small_threshold_create_and_combine_min_inlined(c: &mut Criterion) {
+    for option in &[(2, 3, 32), (3, 5, 32), (5, 9, 32), (2, 3, 64)] {
+        c.bench_function(
+            &format!(
+                "benchmark_create_inlined_min_{}_shares_{}_secret_{}",
+                option.0, option.1, option.2
+            ),
+            |b| {
+                let secret = get_random_bytes(option.2).unwrap();
+                b.iter(|| {
+                    let _ = create_inlined(option.0, option.1, &secret);
+                });
+            },
+        );
+        c.bench_function(
+            &format!(
+                "benchmark_combine_min_inlined_min_{}_shares_{}_secret_{}",
+                option.0, option.1, option.2
+            ),
+            |b| {
+                let secret = get_random_bytes(option.2).unwrap();
+                let shares = create_inlined(option.0, option.1, &secret).unwrap();
+                let min_shares = shares[0..option.0].to_vec();
+                b.iter(|| {
+                    let _ = combine_inlined(min_shares.clone());
+                });
+            },
+        );
+    }
+}
+
+fn benchmark_create_std_vs_dealer_min_3_shares_5_secret_32(c: &mut Criterion) {
+    let secret = get_random_bytes(32).unwrap();
+    let mut group = c.benchmark_group("benchmark_split_min_3_shares_5_secret_32");
+    group.bench_function("create_std", |b| {
+        b.iter(|| {
+            let _ = create_std(3, 5, &secret);
+        });
+    });
+    group.bench_function("dealer", |b| {
+        let mut dealer = Dealer::new().unwrap();
+        b.iter(|| {
+            let _ = dealer.split(3, 5, &secret);
+        });
+    });
+    group.finish();
+}
+
+fn benchmark_create_std_vs_gf256_min_3_shares_5_secret_32(c: &mut Criterion) {
+    let secret = get_random_bytes(32).unwrap();
+    let mut group = c.benchmark_group("benchmark_create_std_vs_gf256_min_3_shares_5_secret_32");
+    group.bench_function("create_std", |b| {
+        b.iter(|| {
+            let _ = create_std(3, 5, &secret);
+        });
+    });
+    group.bench_function("create_gf256", |b| {
+        b.iter(|| {
+            let _ = create_gf256(3, 5, &secret);
+        });
+    });
+    group.finish();
+}
+
+#[cfg(feature = "rayon")]
+fn benchmark_combine_serial_vs_parallel_min_40_shares_80_secret_512(c: &mut Criterion) {
+    let secret = get_random_bytes(512).unwrap();
+    let shares = create_inlined(40, 80, &secret).unwrap();
+    let mut group = c.benchmark_group("benchmark_combine_min_40_shares_80_secret_512");
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            let _ = combine_inlined(shares.clone());
+        });
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| {
+            let _ = shamirss::combine_parallel(shares.clone());
+        });
+    });
+    group.finish();
+}
+
+#[cfg(feature = "rayon")]
+fn benchmark_create_serial_vs_parallel_min_40_shares_60_secret_512(c: &mut Criterion) {
+    let secret = get_random_bytes(512).unwrap();
+    let mut group = c.benchmark_group("benchmark_create_min_40_shares_60_secret_512");
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            let _ = create_inlined(40, 60, &secret);
+        });
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| {
+            let _ = shamirss::create_parallel(40, 60, &secret);
+        });
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     benchmark_create_inlined_min_50_shares_100_secret_512,
     benchmark_combine_all_inlined_min_50_shares_100_secret_512,
+    benchmark_small_threshold_create_and_combine_min_inlined,
+    benchmark_create_std_vs_dealer_min_3_shares_5_secret_32,
+    benchmark_create_std_vs_gf256_min_3_shares_5_secret_32,
+);
+#[cfg(feature = "rayon")]
+criterion_group!(
+    parallel_benches,
+    benchmark_combine_serial_vs_parallel_min_40_shares_80_secret_512,
+    benchmark_create_serial_vs_parallel_min_40_shares_60_secret_512,
 );
+
+#[cfg(not(feature = "rayon"))]
 criterion_main!(benches);
+#[cfg(feature = "rayon")]
+criterion_main!(benches, parallel_benches);
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Shares come from potentially untrusted sources, combining arbitrary bytes shall never panic,
+// only return Ok or Err.
+fuzz_target!(|shares: Vec<Vec<u8>>| {
+    let _ = shamirss::combine_std(shares);
+});
@@ -0,0 +1,124 @@
+//! Shares authenticated with the HMAC-SHA256 tag.
+//! The tag is computed with OpenSSL, already the dependency of the crate, instead of the hmac and
+//! sha2 crates behind a feature, so the module is not feature gated.
+
+use crate::{
+    errors::SSSError,
+    operations::is_proper_size,
+    shamirss::{combine_shares, create_shares},
+};
+use openssl::{hash::MessageDigest, memcmp, pkey::PKey, sign::Signer};
+
+const TAG_SIZE: usize = 32;
+
+/// Creates shares from given secret appending the HMAC-SHA256 tag of every share keyed by given key.
+/// Tag binds the share to the key, so a share altered by anyone without the key is detected
+/// before combining. Shares can be combined only with combine_authenticated.
+///
+/// # Argument
+///
+/// * `min`     - minimal amount of shares required to recreate the secret.
+/// * `total`   - total amount of shares.
+/// * `secret`  - secret to be shared, divisible by 32 without rest.
+/// * `key`     - non empty authentication key, such as a passphrase.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_authenticated, create_authenticated};
+///
+///let secret = vec![7; 64];
+///let shares = create_authenticated(3, 5, &secret, b"passphrase").unwrap();
+///assert_eq!(secret, combine_authenticated(shares[2..].to_vec(), b"passphrase").unwrap());
+///assert!(combine_authenticated(shares[2..].to_vec(), b"other passphrase").is_err());
+///```
+///
+pub fn create_authenticated(
+    min: usize,
+    total: usize,
+    secret: &[u8],
+    key: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if !is_proper_size(secret) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: crate::CHUNK_BYTES,
+        });
+    }
+    let mut shares = create_shares(min, total, secret)?;
+    for share in shares.iter_mut() {
+        let tag = share_tag(share, key)?;
+        share.extend(tag);
+    }
+
+    Ok(shares)
+}
+
+/// Combines shares created with create_authenticated to a secret.
+/// Every share tag is verified in constant time before combining, the index of the first share
+/// failing authentication is returned in the error.
+///
+/// # Argument
+///
+/// * `shares`  - vector of authenticated shares, equal or more the minimal share count.
+/// * `key`     - authentication key the shares were created with.
+///
+pub fn combine_authenticated(shares: Vec<Vec<u8>>, key: &[u8]) -> Result<Vec<u8>, SSSError> {
+    let mut bodies = Vec::with_capacity(shares.len());
+    for (i, share) in shares.iter().enumerate() {
+        let failed = || SSSError::WithReason(format!("share authentication failed at index {i}"));
+        let (body, tag) = share
+            .len()
+            .checked_sub(TAG_SIZE)
+            .map(|size| share.split_at(size))
+            .ok_or_else(failed)?;
+        if !memcmp::eq(&share_tag(body, key)?, tag) {
+            return Err(failed());
+        }
+        bodies.push(body.to_vec());
+    }
+
+    combine_shares(bodies)
+}
+
+#[inline(always)]
+fn share_tag(share: &[u8], key: &[u8]) -> Result<Vec<u8>, SSSError> {
+    if key.is_empty() {
+        return Err(SSSError::WithReason(
+            "Authentication key cannot be empty.".to_owned(),
+        ));
+    }
+    let key = PKey::hmac(key)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(share)?;
+    Ok(signer.sign_to_vec()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::rand::rand_bytes;
+
+    #[test]
+    fn it_should_authenticate_shares_and_reject_tampered_share_by_index() -> Result<(), SSSError> {
+        let mut secret = vec![0; 64];
+        rand_bytes(&mut secret)?;
+        let key = b"correct horse battery staple";
+
+        let mut shares = create_authenticated(3, 5, &secret, key)?;
+        assert_eq!(shares[0].len(), 1 + 2 * secret.len() + TAG_SIZE);
+        assert_eq!(combine_authenticated(shares[1..4].to_vec(), key)?, secret);
+
+        shares[2][5] ^= 1;
+        match combine_authenticated(shares[1..4].to_vec(), key) {
+            Err(SSSError::WithReason(reason)) => {
+                assert_eq!(reason, "share authentication failed at index 1")
+            }
+            other => panic!("expected authentication error, got {other:?}"),
+        }
+        assert!(combine_authenticated(shares[3..].to_vec(), b"wrong key").is_err());
+        assert!(create_authenticated(3, 5, &secret, b"").is_err());
+
+        Ok(())
+    }
+}
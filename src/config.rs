@@ -0,0 +1,82 @@
+use crate::errors::SSSError;
+use openssl::bn::{BigNum, BigNumContext};
+
+/// SplitConfig is a validated pair of the secret block size and the prime shares are calculated
+/// over. Every block of given size shall be smaller than the prime, so the prime shall have more
+/// bits than the block, e.g. 31 bytes blocks with prime below 2^256 or 64 bytes blocks with prime
+/// above 2^512. Share field elements are the prime size in bytes.
+///
+#[derive(Debug)]
+pub struct SplitConfig {
+    block: usize,
+    prime: BigNum,
+}
+
+impl SplitConfig {
+    /// Creates split config from given block size in bytes and prime.
+    ///
+    pub fn new(block: usize, prime: BigNum) -> Result<Self, SSSError> {
+        if block == 0 {
+            return Err(SSSError::WithReason(
+                "Block size cannot be zero.".to_owned(),
+            ));
+        }
+        if prime.num_bits() as usize <= block * 8 {
+            return Err(SSSError::WithReason(format!(
+                "Prime shall be above 2^{}, so every block of {block} bytes is smaller than the prime.",
+                block * 8
+            )));
+        }
+        let mut ctx = BigNumContext::new()?;
+        if !prime.is_prime(64, &mut ctx)? {
+            return Err(SSSError::WithReason(
+                "Custom prime is not a prime number.".to_owned(),
+            ));
+        }
+        Ok(Self { block, prime })
+    }
+
+    /// Returns the secret block size in bytes.
+    ///
+    pub fn block(&self) -> usize {
+        self.block
+    }
+
+    /// Returns the prime shares are calculated over.
+    ///
+    pub fn prime(&self) -> &BigNum {
+        &self.prime
+    }
+
+    /// Returns the share field element size in bytes, x and y coordinates take one element each.
+    ///
+    pub fn element_size(&self) -> usize {
+        self.prime.num_bytes() as usize
+    }
+
+    /// Returns true when the secret is divisible by the block size without rest.
+    ///
+    pub fn is_proper_size(&self, secret: &[u8]) -> bool {
+        secret.len() % self.block == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::LEGACY_PRIME;
+
+    #[test]
+    fn it_should_validate_block_against_prime() -> Result<(), SSSError> {
+        let config = SplitConfig::new(31, BigNum::from_dec_str(LEGACY_PRIME)?)?;
+        assert_eq!(config.element_size(), 32);
+        assert!(config.is_proper_size(&[0; 62]));
+        assert!(!config.is_proper_size(&[0; 64]));
+
+        assert!(SplitConfig::new(32, BigNum::from_dec_str(LEGACY_PRIME)?).is_err());
+        assert!(SplitConfig::new(0, BigNum::from_dec_str(LEGACY_PRIME)?).is_err());
+        assert!(SplitConfig::new(16, BigNum::from_u32(1 << 30)?).is_err());
+
+        Ok(())
+    }
+}
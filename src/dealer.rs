@@ -0,0 +1,102 @@
+use crate::{
+    errors::SSSError,
+    operations::{default_prime, is_proper_size, U8S_TO_BIG_INT_INITIAL},
+    rng::OpensslRng,
+    shamirss::{combine_shares_with_context, create_shares_with_context},
+};
+use openssl::bn::{BigNum, BigNumContext};
+
+/// Dealer creates and combines shares reusing the parsed prime and a single Openssl big num
+/// context, which saves allocating them for every secret when splitting many secrets.
+///
+pub struct Dealer {
+    ctx: BigNumContext,
+    prime: &'static BigNum,
+}
+
+impl Dealer {
+    /// Creates dealer with a fresh big num context.
+    ///
+    pub fn new() -> Result<Self, SSSError> {
+        Ok(Self {
+            ctx: BigNumContext::new()?,
+            prime: default_prime()?,
+        })
+    }
+
+    /// Creates shared secrets from given secret, works as create_std.
+    ///
+    /// # Argument
+    ///
+    /// * `min`     - minimum shares amount needed to recreate the secret.
+    /// * `total`   - total shares amount to create.
+    /// * `secret`  - secret to be shared, divisible by 32 without rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///use shamirss::Dealer;
+    ///
+    ///let mut dealer = Dealer::new().unwrap();
+    ///for secret in [vec![1; 32], vec![2; 64]] {
+    ///    let secret_shares: Vec<Vec<u8>> = dealer.split(2, 3, &secret).unwrap();
+    ///    assert_eq!(secret, dealer.combine(secret_shares[1..].to_vec()).unwrap());
+    ///}
+    ///```
+    ///
+    pub fn split(
+        &mut self,
+        min: usize,
+        total: usize,
+        secret: &[u8],
+    ) -> Result<Vec<Vec<u8>>, SSSError> {
+        if !is_proper_size(secret) {
+            return Err(SSSError::SecretNotAligned {
+                len: secret.len(),
+                block: U8S_TO_BIG_INT_INITIAL,
+            });
+        }
+        create_shares_with_context(
+            &mut self.ctx,
+            &mut OpensslRng,
+            min,
+            total,
+            secret,
+            self.prime,
+        )
+    }
+
+    /// Combines shares to a secret, works as combine_std.
+    ///
+    /// # Argument
+    ///
+    /// * `shares`  - vector of shares, equal or more the minimal share count.
+    ///
+    pub fn combine(&mut self, shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+        combine_shares_with_context(&mut self.ctx, shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combine_std, create_std};
+
+    #[test]
+    fn it_should_split_and_combine_many_secrets_with_one_dealer() -> Result<(), SSSError> {
+        let mut dealer = Dealer::new()?;
+        for i in 0..20u8 {
+            let secret = vec![i; 32 * (1 + i as usize % 4)];
+            let shares = dealer.split(3, 5, &secret)?;
+            assert_eq!(dealer.combine(shares[..3].to_vec())?, secret);
+            assert_eq!(combine_std(shares[2..].to_vec())?, secret);
+
+            let shares = create_std(3, 5, &secret)?;
+            assert_eq!(dealer.combine(shares[1..4].to_vec())?, secret);
+        }
+        assert!(dealer.split(3, 5, &[1; 33]).is_err());
+        assert!(dealer.split(6, 5, &[1; 32]).is_err());
+
+        Ok(())
+    }
+}
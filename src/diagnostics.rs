@@ -0,0 +1,70 @@
+use crate::CHUNK_BYTES;
+
+/// Fewest distinct byte values expected in a secret of at least one chunk.
+///
+const MIN_DISTINCT_BYTES: usize = 8;
+
+/// Returns warning when the secret looks like a placeholder or is obviously low entropy.
+/// Check is advisory and meant to be called before splitting, a secret passing it is not
+/// guaranteed to be random.
+///
+/// # Argument
+///
+/// * `secret`  - secret to be shared.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::warn_weak_secret;
+///
+///assert!(warn_weak_secret(&[0; 32]).is_some());
+///```
+///
+pub fn warn_weak_secret(secret: &[u8]) -> Option<&'static str> {
+    let first = secret.first()?;
+    if secret.iter().all(|b| b == first) {
+        return Some("Secret consists of a single repeated byte.");
+    }
+    if secret.len() > CHUNK_BYTES
+        && secret
+            .chunks(CHUNK_BYTES)
+            .all(|chunk| chunk == &secret[..chunk.len()])
+    {
+        return Some("Secret repeats the same chunk.");
+    }
+    if secret.len() >= CHUNK_BYTES {
+        let mut seen = [false; 256];
+        secret.iter().for_each(|b| seen[*b as usize] = true);
+        if seen.iter().filter(|s| **s).count() < MIN_DISTINCT_BYTES {
+            return Some("Secret uses very few distinct byte values.");
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::SSSError;
+    use openssl::rand::rand_bytes;
+
+    #[test]
+    fn it_should_warn_about_weak_secrets() {
+        assert!(warn_weak_secret(&[0; 64]).is_some());
+        assert!(warn_weak_secret(&[0xff; 32]).is_some());
+        let pattern: Vec<u8> = (0..32).collect();
+        assert!(warn_weak_secret(&pattern.repeat(3)).is_some());
+        assert!(warn_weak_secret(&[0, 1].repeat(16)).is_some());
+        assert!(warn_weak_secret(&[]).is_none());
+    }
+
+    #[test]
+    fn it_should_not_warn_about_random_secret() -> Result<(), SSSError> {
+        let mut secret = vec![0; 64];
+        rand_bytes(&mut secret)?;
+        assert_eq!(warn_weak_secret(&secret), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    errors::SSSError,
+    shamirss::{combine_shares, create_shares},
+};
+use openssl::{
+    rand::rand_bytes,
+    symm::{decrypt_aead, encrypt_aead, Cipher},
+};
+
+const DATA_KEY_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+const TAG_SIZE: usize = 16;
+
+/// Envelope holds secret encrypted with AES-256-GCM and the shares of the data key.
+/// Authentication tag is appended to the ciphertext.
+///
+#[derive(Debug, Clone)]
+pub struct Envelope {
+    pub ciphertext: Vec<u8>,
+    pub nonce: Vec<u8>,
+    pub key_shares: Vec<Vec<u8>>,
+}
+
+/// Encrypts secret with random data key and splits only the data key to shares.
+/// Shares size does not depend on secret size.
+///
+/// # Argument
+///
+/// * `secret`              - bytes slice of secret of any size to encrypt.
+/// * `min_shares_count`    - minimal amount of key shares required to decrypt the secret.
+/// * `total_shares_count`  - total amount of key shares.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_envelope, split_envelope};
+///
+///let secret = b"secret of any size";
+///let envelope = split_envelope(secret, 3, 5).unwrap();
+///let secret_recreated = combine_envelope(&envelope, envelope.key_shares[0..3].to_vec()).unwrap();
+///assert_eq!(secret.to_vec(), secret_recreated);
+///```
+///
+pub fn split_envelope(
+    secret: &[u8],
+    min_shares_count: usize,
+    total_shares_count: usize,
+) -> Result<Envelope, SSSError> {
+    let mut key = [0; DATA_KEY_SIZE];
+    rand_bytes(&mut key)?;
+    let mut nonce = vec![0; NONCE_SIZE];
+    rand_bytes(&mut nonce)?;
+
+    let key_shares = create_shares(min_shares_count, total_shares_count, &key)?;
+
+    let mut tag = [0; TAG_SIZE];
+    let mut ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        &key,
+        Some(&nonce),
+        &[],
+        secret,
+        &mut tag,
+    )?;
+    ciphertext.extend(tag);
+
+    Ok(Envelope {
+        ciphertext,
+        nonce,
+        key_shares,
+    })
+}
+
+/// Combines data key from given key shares and decrypts envelope ciphertext.
+///
+/// # Argument
+///
+/// * `envelope`    - envelope holding the ciphertext and nonce.
+/// * `key_shares`  - vector of key shares, equal or more the minimal key share count.
+///
+pub fn combine_envelope(
+    envelope: &Envelope,
+    key_shares: Vec<Vec<u8>>,
+) -> Result<Vec<u8>, SSSError> {
+    if envelope.ciphertext.len() < TAG_SIZE {
+        return Err(SSSError::WithReason(
+            "Envelope ciphertext is shorter then authentication tag.".to_owned(),
+        ));
+    }
+    let key = combine_shares(key_shares)?;
+    if key.len() != DATA_KEY_SIZE {
+        return Err(SSSError::WithReason(format!(
+            "Data key shall be {DATA_KEY_SIZE} bytes long, got {}.",
+            key.len()
+        )));
+    }
+
+    let (ciphertext, tag) = envelope
+        .ciphertext
+        .split_at(envelope.ciphertext.len() - TAG_SIZE);
+
+    Ok(decrypt_aead(
+        Cipher::aes_256_gcm(),
+        &key,
+        Some(&envelope.nonce),
+        &[],
+        ciphertext,
+        tag,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_split_and_combine_envelope() -> Result<(), SSSError> {
+        let mut secret = vec![0; 10 * 1024];
+        rand_bytes(&mut secret)?;
+
+        let envelope = split_envelope(&secret, 3, 5)?;
+        assert_eq!(envelope.key_shares.len(), 5);
+        assert_eq!(envelope.ciphertext.len(), secret.len() + TAG_SIZE);
+
+        let recreated = combine_envelope(&envelope, envelope.key_shares[1..4].to_vec())?;
+        assert_eq!(recreated, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_combine_tampered_envelope() -> Result<(), SSSError> {
+        let secret = vec![1; 1024];
+        let mut envelope = split_envelope(&secret, 3, 5)?;
+        envelope.ciphertext[10] ^= 1;
+
+        assert!(combine_envelope(&envelope, envelope.key_shares[0..3].to_vec()).is_err());
+
+        Ok(())
+    }
+}
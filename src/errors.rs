@@ -1,5 +1,27 @@
 use thiserror::Error;
 
+/// Base58Error is returned when decoding a string that is not valid base58.
+///
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base58Error {
+    #[error("invalid character {c:?} at position {index}")]
+    InvalidCharacter { c: char, index: usize },
+}
+
+/// Base32Error is returned when decoding a string that is not valid RFC 4648 base32.
+///
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base32Error {
+    #[error("invalid character {c:?} at position {index}")]
+    InvalidCharacter { c: char, index: usize },
+    #[error("invalid length {0}")]
+    InvalidLength(usize),
+    #[error("invalid padding")]
+    InvalidPadding,
+    #[error("non-zero trailing bits")]
+    InvalidTrailingBits,
+}
+
 /// SSSErrors contains all the errors types that are returned in Result.
 ///
 #[derive(Error, Debug)]
@@ -10,6 +32,65 @@ pub enum SSSError {
     FromHex(#[from] hex::FromHexError),
     #[error("failed with base64 operation: {0}")]
     FromBase64(#[from] base64::DecodeError),
+    #[error("failed with base58 operation: {0}")]
+    FromBase58(#[from] Base58Error),
+    #[error("failed with base32 operation: {0}")]
+    FromBase32(#[from] Base32Error),
+    #[error("failed with io operation: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("minimum shares count {min} cannot be bigger then total shares {shares}")]
+    MinGreaterThanShares { min: usize, shares: usize },
+    #[error("secret size {len} should be divisible by {block} without rest")]
+    SecretNotAligned { len: usize, block: usize },
+    #[error("share size {len} should be divisible by {block} without rest")]
+    ShareNotAligned { len: usize, block: usize },
+    #[error("all shares shall have the same size of {expected}, got {got}")]
+    ShareSizeMismatch { expected: usize, got: usize },
+    /// Lagrange denominator has no modular inverse, which happens when two of the combined shares
+    /// have the same x-coordinate.
+    #[error("shares have the same x-coordinate, denominator is not invertible")]
+    NonInvertible,
     #[error("failed with reason: {0}")]
     WithReason(String),
 }
+
+/// SSSErrorKind classifies errors, so callers can match on failures they can act on.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SSSErrorKind {
+    Openssl,
+    Hex,
+    Base64,
+    Base58,
+    Base32,
+    Io,
+    MinGreaterThanShares,
+    SecretNotAligned,
+    ShareNotAligned,
+    ShareSizeMismatch,
+    /// Combined shares contain the same x-coordinate, combining a different subset of shares
+    /// may succeed.
+    NonInvertible,
+    Other,
+}
+
+impl SSSError {
+    /// Returns kind of the error.
+    ///
+    pub fn kind(&self) -> SSSErrorKind {
+        match self {
+            Self::FromOpenssl(_) => SSSErrorKind::Openssl,
+            Self::FromHex(_) => SSSErrorKind::Hex,
+            Self::FromBase64(_) => SSSErrorKind::Base64,
+            Self::FromBase58(_) => SSSErrorKind::Base58,
+            Self::FromBase32(_) => SSSErrorKind::Base32,
+            Self::Io(_) => SSSErrorKind::Io,
+            Self::MinGreaterThanShares { .. } => SSSErrorKind::MinGreaterThanShares,
+            Self::SecretNotAligned { .. } => SSSErrorKind::SecretNotAligned,
+            Self::ShareNotAligned { .. } => SSSErrorKind::ShareNotAligned,
+            Self::ShareSizeMismatch { .. } => SSSErrorKind::ShareSizeMismatch,
+            Self::NonInvertible => SSSErrorKind::NonInvertible,
+            Self::WithReason(_) => SSSErrorKind::Other,
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{errors::SSSError, operations::wipe_bytes, shamirss::MIN_SHARES_COUNT};
+use openssl::rand::rand_bytes;
+
+const INDEX_SIZE: usize = 1;
+
+/// Multiplies in GF(2^8) reduced by the AES polynomial x^8 + x^4 + x^3 + x + 1 (0x11b).
+/// Shift and xor over all eight bits selected with masks, without branches or table lookups,
+/// so the time does not depend on the secret bytes.
+///
+#[inline(always)]
+fn mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    for _ in 0..8 {
+        product ^= a & (b & 1).wrapping_neg();
+        let carry = (a >> 7).wrapping_neg();
+        a = (a << 1) ^ (0x1b & carry);
+        b >>= 1;
+    }
+    product
+}
+
+/// Returns the inverse of a as a^254, which is 0 for 0. Fixed exponent makes the same squarings
+/// and multiplications for every a.
+///
+#[inline(always)]
+fn inv(a: u8) -> u8 {
+    // a^254 = a^2 * a^4 * ... * a^128
+    let mut power = a;
+    let mut inverse = 1;
+    for _ in 0..7 {
+        power = mul(power, power);
+        inverse = mul(inverse, power);
+    }
+    inverse
+}
+
+/// Divides a by non zero b.
+///
+#[inline(always)]
+fn div(a: u8, b: u8) -> u8 {
+    mul(a, inv(b))
+}
+
+/// Creates shares from given secret of any size with byte-wise Shamir's secret sharing over GF(2^8).
+/// The i-th share starts with the share index i + 1 followed by one byte per secret byte,
+/// so shares are the secret size plus one byte. Shares can be combined only with combine_gf256.
+///
+/// # Argument
+///
+/// * `min`     - minimal amount of shares required to recreate the secret.
+/// * `total`   - total amount of shares, up to 255.
+/// * `secret`  - non empty secret to be shared.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_gf256, create_gf256};
+///
+///let secret = b"tiny secret".to_vec();
+///let shares = create_gf256(3, 5, &secret).unwrap();
+///assert_eq!(shares[0].len(), secret.len() + 1);
+///assert_eq!(secret, combine_gf256(shares[2..].to_vec()).unwrap());
+///```
+///
+pub fn create_gf256(min: usize, total: usize, secret: &[u8]) -> Result<Vec<Vec<u8>>, SSSError> {
+    if secret.is_empty() {
+        return Err(SSSError::WithReason("Secret cannot be empty.".to_owned()));
+    }
+    if min > total {
+        return Err(SSSError::MinGreaterThanShares { min, shares: total });
+    }
+    if min < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "Minimum value cannot be smaller then {MIN_SHARES_COUNT}."
+        )));
+    }
+    if total > u8::MAX as usize {
+        return Err(SSSError::WithReason(format!(
+            "Total shares cannot be bigger then {} for GF(256) shares.",
+            u8::MAX
+        )));
+    }
+
+    let mut shares: Vec<Vec<u8>> = (1..=total as u8)
+        .map(|x| {
+            let mut share = Vec::with_capacity(INDEX_SIZE + secret.len());
+            share.push(x);
+            share
+        })
+        .collect();
+    let mut coefficients = vec![0u8; min - 1];
+    for b in secret.iter() {
+        if let Err(e) = rand_bytes(&mut coefficients) {
+            wipe_bytes(&mut coefficients);
+            return Err(e.into());
+        }
+        for share in shares.iter_mut() {
+            let x = share[0];
+            let y = coefficients
+                .iter()
+                .rev()
+                .fold(0, |y, coefficient| mul(y, x) ^ coefficient);
+            share.push(mul(y, x) ^ b);
+        }
+    }
+    wipe_bytes(&mut coefficients);
+
+    Ok(shares)
+}
+
+/// Combines shares created with create_gf256 to a secret.
+/// Shares with the same index and body are combined once, the same index with other body is
+/// rejected.
+///
+/// # Argument
+///
+/// * `shares`  - vector of GF(256) shares, equal or more the minimal share count.
+///
+pub fn combine_gf256(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    let size = match shares.first() {
+        Some(share) if share.len() > INDEX_SIZE => share.len(),
+        Some(_) => {
+            return Err(SSSError::WithReason(
+                "share contains no secret data after index".to_owned(),
+            ))
+        }
+        None => return Err(SSSError::WithReason("no shares provided".to_owned())),
+    };
+
+    let mut distinct: Vec<&[u8]> = Vec::with_capacity(shares.len());
+    for (i, share) in shares.iter().enumerate() {
+        if share.len() != size {
+            return Err(SSSError::ShareSizeMismatch {
+                expected: size,
+                got: share.len(),
+            });
+        }
+        if share[0] == 0 {
+            return Err(SSSError::WithReason(format!(
+                "Share at index {i} has share index 0."
+            )));
+        }
+        match distinct.iter().find(|other| other[0] == share[0]) {
+            Some(other) if **other == share[..] => continue,
+            Some(_) => {
+                return Err(SSSError::WithReason(format!(
+                    "Share at index {i} has share index {} of other share with different body.",
+                    share[0]
+                )))
+            }
+            None => distinct.push(share),
+        }
+    }
+    if distinct.len() < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "At least {MIN_SHARES_COUNT} distinct shares are required to combine, got {}.",
+            distinct.len()
+        )));
+    }
+
+    // Lagrange basis polynomials at 0 are the same for every secret byte.
+    let basis: Vec<u8> = distinct
+        .iter()
+        .map(|share| {
+            distinct
+                .iter()
+                .filter(|other| other[0] != share[0])
+                .fold(1, |basis, other| {
+                    mul(basis, div(other[0], other[0] ^ share[0]))
+                })
+        })
+        .collect();
+
+    Ok((INDEX_SIZE..size)
+        .map(|k| {
+            distinct
+                .iter()
+                .zip(basis.iter())
+                .fold(0, |secret, (share, basis)| secret ^ mul(share[k], *basis))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_multiply_and_divide_in_aes_field() {
+        assert_eq!(mul(0x57, 0x83), 0xc1);
+        assert_eq!(mul(0x53, 0xca), 0x01);
+        assert_eq!(inv(0x53), 0xca);
+        assert_eq!(inv(0), 0);
+        for a in 1..=u8::MAX {
+            assert_eq!(mul(div(1, a), a), 1);
+            assert_eq!(div(mul(a, 0x1d), 0x1d), a);
+        }
+    }
+
+    #[test]
+    fn it_should_combine_gf256_shares_across_thresholds() -> Result<(), SSSError> {
+        let mut secret = vec![0; 33];
+        rand_bytes(&mut secret)?;
+        for min in 2..=6 {
+            let shares = create_gf256(min, min + 3, &secret)?;
+            assert_eq!(shares.len(), min + 3);
+            assert!(shares.iter().all(|share| share.len() == secret.len() + 1));
+            assert_eq!(combine_gf256(shares[..min].to_vec())?, secret);
+            assert_eq!(combine_gf256(shares[3..].to_vec())?, secret);
+            if min > MIN_SHARES_COUNT {
+                assert_ne!(combine_gf256(shares[1..min].to_vec())?, secret);
+            }
+        }
+
+        let shares = create_gf256(2, 255, &secret)?;
+        assert_eq!(
+            combine_gf256(vec![shares[254].clone(), shares[0].clone()])?,
+            secret
+        );
+        assert!(create_gf256(2, 256, &secret).is_err());
+        assert!(create_gf256(2, 3, &[]).is_err());
+
+        let mut conflicting = shares[0].clone();
+        conflicting[1] ^= 1;
+        assert!(combine_gf256(vec![shares[0].clone(), conflicting, shares[1].clone()]).is_err());
+
+        Ok(())
+    }
+}
@@ -1,16 +1,100 @@
+mod auth;
+mod config;
+mod dealer;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod envelope;
 pub mod errors;
+mod gf256;
+mod mandatory;
 mod operations;
+mod pem;
+mod public_check;
+mod reshare;
+mod rng;
 mod shamirss;
+mod share;
+mod stream;
+mod threshold;
+mod vss;
+pub use auth::{combine_authenticated, create_authenticated};
+pub use config::SplitConfig;
+pub use dealer::Dealer;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::warn_weak_secret;
+pub use envelope::{combine_envelope, split_envelope, Envelope};
 use errors::SSSError;
+pub use gf256::{combine_gf256, create_gf256};
+pub use mandatory::{combine_shares_with_mandatory, create_shares_with_mandatory, MandatoryShares};
 use operations::{
-    is_proper_size, secret_base64_to_bytes, secret_bytes_to_base64, secret_bytes_to_hex,
-    secret_hex_to_bytes, shares_base64_to_bytes, shares_bytes_to_base64, shares_bytes_to_hex,
-    shares_hex_to_bytes, U8S_TO_BIG_INT_INITIAL,
+    bytes_to_hex_grouped, crc32, custom_base64, custom_base64_strict, is_proper_size,
+    parse_share_label, read_framed_shares, secret_base32_to_bytes, secret_base58_to_bytes,
+    secret_base64_to_bytes, secret_base64_to_bytes_with, secret_bytes_to_base32,
+    secret_bytes_to_base58, secret_bytes_to_base64, secret_bytes_to_base64_with,
+    secret_bytes_to_hex, secret_hex_to_bytes, shares_base32_to_bytes,
+    shares_base32_to_bytes_strict, shares_base58_to_bytes, shares_base58_to_bytes_strict,
+    shares_base64_to_bytes, shares_base64_to_bytes_strict, shares_base64_to_bytes_strict_with,
+    shares_base64_to_bytes_with, shares_bytes_to_base32, shares_bytes_to_base58,
+    shares_bytes_to_base64, shares_bytes_to_base64_with, shares_bytes_to_hex, shares_hex_to_bytes,
+    shares_hex_to_bytes_strict, wipe_bytes, CHECKSUM_SIZE, U8S_TO_BIG_INT_INITIAL, URL_SAFE_BASE64,
 };
+pub use pem::{decode_share_pem, encode_share_pem, ShareMeta};
+pub use public_check::{create_shares_with_public_check, verify_with_public_check, PublicCheck};
+pub use reshare::reshare;
+pub use rng::{OpensslRng, ShareRng};
+pub use shamirss::interpolate_at;
+pub use share::{Share, ShareSet};
+pub use stream::{combine_readers, split_reader};
+pub use threshold::Threshold;
+pub use vss::{create_verifiable, verify_share, Commitments};
+
+/// Size in bytes of the secret chunk mapped to a single field element.
+/// Secret size shall be divisible by this value.
+///
+pub const CHUNK_BYTES: usize = U8S_TO_BIG_INT_INITIAL;
+
+/// Maximum size in bytes of a single field element stored in a share.
+///
+pub const MAX_FIELD_BYTES: usize = U8S_TO_BIG_INT_INITIAL;
+
+/// Count of field elements stored in a share per secret chunk, the x and y coordinates.
+///
+pub const COEFFICIENTS_PER_SHARE: usize = shamirss::COEFFICIENTS_PER_SHARE;
+
+/// Size in bytes of the share body per secret chunk.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, CHUNK_BYTES, SHARE_HEADER_BYTES, SHARE_UNIT_BYTES};
+///
+///let secret = vec![7; 512];
+///let expected_share_size = SHARE_HEADER_BYTES + secret.len() / CHUNK_BYTES * SHARE_UNIT_BYTES;
+///
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///for share in secret_shares.iter() {
+///    assert_eq!(share.len(), expected_share_size);
+///}
+///```
+///
+pub const SHARE_UNIT_BYTES: usize = shamirss::COEFFICIENTS_SIZE;
+
+/// Size in bytes of the share header holding the share format version.
+///
+pub const SHARE_HEADER_BYTES: usize = shamirss::SHARE_HEADER_SIZE;
+
+/// Size in bytes of the secret length header prefixed by create_padded.
+///
+const PADDED_LENGTH_BYTES: usize = 8;
+
+/// Size in bytes of the BIP39 entropy backing a 24-word mnemonic.
+///
+pub const BIP39_ENTROPY_BYTES: usize = 32;
 
 /// Creates shared secrets from given secret.
 /// Function will not be inlined.
 /// Can calculate shares for secret divisible by 32 without rest (secret_size mod 32 == 0).
+/// Every share starts with the share format version byte.
 ///
 /// # Argument
 ///
@@ -57,20 +141,91 @@ pub fn create_std(
     secret: &[u8],
 ) -> Result<Vec<Vec<u8>>, errors::SSSError> {
     if !is_proper_size(secret) {
-        return Err(errors::SSSError::WithReason(format!(
-            "Secret size should be divisible by {U8S_TO_BIG_INT_INITIAL} without rest"
-        )));
+        return Err(errors::SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
     }
     shamirss::create_shares(min_shares_count, total_shares_count, secret)
 }
 
+/// Creates shared secrets from given secret using validated threshold.
+/// Works as create_std, but minimum and total shares amount cannot be swapped by mistake.
+///
+/// # Argument
+///
+/// * `threshold`  - validated minimum and total shares amount.
+/// * `secret`     - secret to be shared, divisible by 32 without rest.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std, create_std_t, Threshold};
+///
+///let secret = vec![7; 64];
+///let threshold = Threshold::new(3, 5).unwrap();
+///let secret_shares: Vec<Vec<u8>> = create_std_t(threshold, &secret).unwrap();
+///assert_eq!(secret_shares.len(), 5);
+///assert_eq!(secret, combine_std(secret_shares[..3].to_vec()).unwrap());
+///```
+///
+pub fn create_std_t(threshold: Threshold, secret: &[u8]) -> Result<Vec<Vec<u8>>, SSSError> {
+    create_std(threshold.min(), threshold.total(), secret)
+}
+
+/// Creates shared secrets from given secret drawing polynomial coefficients and x-coordinates
+/// from given random numbers source. Works as create_std, which uses OpensslRng.
+/// Source shall be cryptographically secure, predictable numbers reveal the secret,
+/// deterministic source is meant for tests only.
+///
+/// # Argument
+///
+/// * `min`     - minimum shares amount needed to recreate the secret.
+/// * `total`   - total shares amount to create.
+/// * `secret`  - secret to be shared, divisible by 32 without rest.
+/// * `rng`     - random numbers source.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std, create_std_with_rng, OpensslRng};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_std_with_rng(2, 3, &secret, &mut OpensslRng).unwrap();
+///assert_eq!(secret, combine_std(secret_shares[1..].to_vec()).unwrap());
+///```
+///
+pub fn create_std_with_rng<R: ShareRng>(
+    min: usize,
+    total: usize,
+    secret: &[u8],
+    rng: &mut R,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if !is_proper_size(secret) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
+    }
+    let mut ctx = openssl::bn::BigNumContext::new()?;
+    shamirss::create_shares_with_context(
+        &mut ctx,
+        rng,
+        min,
+        total,
+        secret,
+        operations::default_prime()?,
+    )
+}
+
 /// Combines shares to a secrets.
 /// Function will not be inlined.
+/// Accepts versioned shares as well as legacy shares created without the format version byte.
 ///
 /// # Argument
 ///
 /// * `shares`  - vector of shares to reconstruct the secret. Shall be equal or more the minimal
-/// share count required to re-create the secret used for crating shares.
+///   share count required to re-create the secret used for crating shares.
 ///
 /// # Examples
 ///
@@ -110,176 +265,207 @@ pub fn combine_std(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, errors::SSSError> {
     shamirss::combine_shares(shares)
 }
 
-/// Creates shared secrets from given secret.
-/// Function will be inlined.
-/// Can calculate shares for secret divisible by 32 without rest (secret_size mod 32 == 0).
+/// Creates shared secrets from given secret as a typed share set, works as create_std.
+/// With the `serde` feature the share set serializes to a sequence of base64 strings.
 ///
 /// # Argument
 ///
-/// * `min_shares_count`    - minimal amount of shares required to reconstruct the secret.
-/// * `total_shares_count`  - total amount of shares.
-/// * `secret`              - bytes slice of secret to create shares from.
+/// * `min_shares_count`     - minimum shares amount needed to recreate the secret.
+/// * `total_shares_count`   - total shares amount to create.
+/// * `secret`               - secret to be shared, divisible by 32 without rest.
 ///
 /// # Examples
 ///
 /// ```
-///use shamirss::{create_inlined};
+///use shamirss::{combine_typed, create_shares_typed, ShareSet};
 ///
-///const SECRET_512_BYTES: &[u8; 512] = &[
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129, 160,
-///    99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96, 116,
-///    133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0, 29,
-///    136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129,
-///    160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96,
-///    116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0,
-///    29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93,
-///    129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173,
-///    202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0,
-///    0, 0, 0, 29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100,
-///    100, 93, 129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25,
-///    64, 173, 202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0,
-///    0, 0, 0, 0, 0, 0, 29, 136,
-///];
+///let secret = [7; 64];
+///let share_set: ShareSet = create_shares_typed(2, 3, &secret).unwrap();
+///assert_eq!(secret.to_vec(), combine_typed(share_set).unwrap());
+///```
 ///
-/// let _secret_shares: Vec<Vec<u8>> = create_inlined(50, 100, SECRET_512_BYTES).unwrap();
+pub fn create_shares_typed(
+    min_shares_count: usize,
+    total_shares_count: usize,
+    secret: &[u8],
+) -> Result<ShareSet, SSSError> {
+    ShareSet::from_bytes(&create_std(min_shares_count, total_shares_count, secret)?)
+}
+
+/// Combines typed share set to a secret, works as combine_std.
+///
+/// # Argument
+///
+/// * `shares`  - share set, equal or more the minimal share count.
+///
+pub fn combine_typed(shares: ShareSet) -> Result<Vec<u8>, SSSError> {
+    combine_std(shares.into_bytes())
+}
+
+/// Creates shared secrets from given secret writing the minimum shares count to every share header.
+/// Shares start with format version 3 byte followed by the minimum shares count byte,
+/// they are combined with combine_std as well as with combine_checked.
+///
+/// # Argument
+///
+/// * `min_shares_count`     - minimum shares amount needed to recreate the secret, up to 255.
+/// * `total_shares_count`   - total shares amount to create.
+/// * `secret`               - secret to be shared, divisible by 32 without rest.
 ///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_checked, create_shares_with_header};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_shares_with_header(3, 5, &secret).unwrap();
+///assert_eq!(secret, combine_checked(secret_shares[2..].to_vec()).unwrap());
+///assert!(combine_checked(secret_shares[..2].to_vec()).is_err());
 ///```
 ///
-#[inline(always)]
-pub fn create_inlined(
+pub fn create_shares_with_header(
     min_shares_count: usize,
     total_shares_count: usize,
     secret: &[u8],
-) -> Result<Vec<Vec<u8>>, errors::SSSError> {
+) -> Result<Vec<Vec<u8>>, SSSError> {
     if !is_proper_size(secret) {
-        return Err(errors::SSSError::WithReason(format!(
-            "Secret size should be divisible by {U8S_TO_BIG_INT_INITIAL} without rest"
-        )));
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
     }
-    shamirss::create_shares(min_shares_count, total_shares_count, secret)
+    shamirss::create_shares_with_header(min_shares_count, total_shares_count, secret)
 }
 
-/// Combines shares to a secrets.
-/// Function will be inlined.
+/// Combines shares created with create_shares_with_header to a secret.
+/// Fewer distinct shares than the minimum shares count in the header are rejected with
+/// insufficient shares error, instead of recreating a wrong secret.
 ///
 /// # Argument
 ///
-/// * `shares`  - vector of shares to reconstruct the secret. Shall be equal or more the minimal
-/// share count required to re-create the secret used for crating shares.
+/// * `shares`  - vector of shares with threshold header, equal or more the minimal share count.
+///
+pub fn combine_checked(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    shamirss::combine_shares_checked(shares)
+}
+
+/// Creates shares from given secret split to blocks of the configured size and calculated over
+/// the configured prime. Prime shall have more bits than the block, so every block is smaller than
+/// the prime, e.g. 31 bytes blocks guarantee it for the prime below 2^256.
+/// Config is not stored in the shares, shares can be combined only with combine_with_config and
+/// the same config.
+///
+/// # Argument
+///
+/// * `min`     - minimum shares amount needed to recreate the secret.
+/// * `total`   - total shares amount to create.
+/// * `secret`  - secret to be shared, divisible by the configured block size without rest.
+/// * `config`  - block size and prime.
 ///
 /// # Examples
 ///
 /// ```
-///use shamirss::{combine_inlined, create_inlined};
-///
-///const SECRET_512_BYTES: &[u8; 512] = &[
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129, 160,
-///    99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96, 116,
-///    133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0, 29,
-///    136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129,
-///    160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96,
-///    116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0,
-///    29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93,
-///    129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173,
-///    202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0,
-///    0, 0, 0, 29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100,
-///    100, 93, 129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25,
-///    64, 173, 202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0,
-///    0, 0, 0, 0, 0, 0, 29, 136,
-///];
+///use openssl::bn::BigNum;
+///use shamirss::{combine_with_config, create_with_config, SplitConfig};
 ///
-/// let secret_shares: Vec<Vec<u8>> = create_inlined(50, 100, SECRET_512_BYTES).unwrap();
-/// let secret_recreated = combine_inlined(secret_shares).unwrap();
-/// assert_eq!(SECRET_512_BYTES.to_vec(), secret_recreated);
+///let prime = BigNum::from_dec_str("340282366920938463463374607431768211507").unwrap();
+///let config = SplitConfig::new(16, prime).unwrap();
+///let secret = vec![7; 48];
+///let secret_shares: Vec<Vec<u8>> = create_with_config(2, 3, &secret, &config).unwrap();
+///assert_eq!(secret, combine_with_config(secret_shares[1..].to_vec(), &config).unwrap());
 ///```
 ///
-#[inline(always)]
-pub fn combine_inlined(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, errors::SSSError> {
-    shamirss::combine_shares(shares)
+pub fn create_with_config(
+    min: usize,
+    total: usize,
+    secret: &[u8],
+    config: &SplitConfig,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if !config.is_proper_size(secret) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: config.block(),
+        });
+    }
+    shamirss::create_shares_with_config(min, total, secret, config)
 }
 
-/// Encoding standard for secret and shares.
+/// Combines shares created with create_with_config using the same config.
 ///
-#[derive(Debug, Clone)]
-pub enum EncodingStd {
-    Hex,
-    Base64,
+/// # Argument
+///
+/// * `shares`  - vector of shares, equal or more the minimal share count.
+/// * `config`  - block size and prime the shares were created with.
+///
+pub fn combine_with_config(
+    shares: Vec<Vec<u8>>,
+    config: &SplitConfig,
+) -> Result<Vec<u8>, SSSError> {
+    shamirss::combine_shares_with_config(shares, config)
 }
 
-/// Encodes secret bytes to string in given encoding standard.
+/// Creates indexed shares from given secret, the i-th share is evaluated at x = i + 1.
+/// Every share holds the one byte share index followed by 32 bytes per secret chunk, so shares
+/// are half the size of create_std shares. Shares shall be combined with combine_indexed.
 ///
 /// # Argument
 ///
-/// * `b`  - secret bytes to encode.
+/// * `min_shares_count`     - minimum shares amount needed to recreate the secret.
+/// * `total_shares_count`   - total shares amount to create, up to 255.
+/// * `secret`               - secret to be shared, divisible by 32 without rest.
 ///
 /// # Examples
 ///
 /// ```
-///use shamirss::{encode_secret_bytes, EncodingStd};
+///use shamirss::{combine_indexed, create_indexed};
 ///
-///const secret_bytes: &[u8; 512] = &[
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129, 160,
-///    99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96, 116,
-///    133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0, 29,
-///    136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129,
-///    160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96,
-///    116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0,
-///    29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93,
-///    129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173,
-///    202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0,
-///    0, 0, 0, 29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100,
-///    100, 93, 129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0,
-///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25,
-///    64, 173, 202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0,
-///    0, 0, 0, 0, 0, 0, 29, 136,
-///];
-///
-///let encoding = EncodingStd::Hex;
-///let enc = encode_secret_bytes(secret_bytes, encoding);
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_indexed(3, 5, &secret).unwrap();
+///assert_eq!(secret_shares[4][0], 5);
+///assert_eq!(secret, combine_indexed(secret_shares[2..].to_vec()).unwrap());
 ///```
 ///
-pub fn encode_secret_bytes(b: &[u8], encoding: EncodingStd) -> String {
-    match encoding {
-        EncodingStd::Hex => secret_bytes_to_hex(b),
-        EncodingStd::Base64 => secret_bytes_to_base64(b),
+pub fn create_indexed(
+    min_shares_count: usize,
+    total_shares_count: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if !is_proper_size(secret) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
     }
+    shamirss::create_shares_indexed(min_shares_count, total_shares_count, secret)
 }
 
-/// Decodes secret to bytes from string in given encoding standard.
+/// Combines shares created with create_indexed to a secret.
 ///
 /// # Argument
 ///
-/// * `s`  - secret string to decode.
+/// * `shares`  - vector of indexed shares, equal or more the minimal share count.
+///
+pub fn combine_indexed(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    shamirss::combine_shares_indexed(shares)
+}
+
+/// Creates shared secrets from given secret.
+/// Function will be inlined.
+/// Can calculate shares for secret divisible by 32 without rest (secret_size mod 32 == 0).
+/// Every share starts with the share format version byte.
+///
+/// # Argument
+///
+/// * `min_shares_count`    - minimal amount of shares required to reconstruct the secret.
+/// * `total_shares_count`  - total amount of shares.
+/// * `secret`              - bytes slice of secret to create shares from.
 ///
 /// # Examples
 ///
 /// ```
-///use shamirss::{encode_secret_bytes, decode_secret_to_bytes, EncodingStd};
+///use shamirss::{create_inlined};
 ///
-///const secret_bytes: &[u8; 512] = &[
+///const SECRET_512_BYTES: &[u8; 512] = &[
 ///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 ///    110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129, 160,
 ///    99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -303,59 +489,1396 @@ pub fn encode_secret_bytes(b: &[u8], encoding: EncodingStd) -> String {
 ///    0, 0, 0, 0, 0, 0, 29, 136,
 ///];
 ///
-///let encoding = EncodingStd::Hex;
-///let enc = encode_secret_bytes(secret_bytes, encoding.clone());
-
-///let _dec = decode_secret_to_bytes(&enc, encoding).unwrap();
+/// let _secret_shares: Vec<Vec<u8>> = create_inlined(50, 100, SECRET_512_BYTES).unwrap();
+///
 ///```
 ///
-pub fn decode_secret_to_bytes(s: &str, encoding: EncodingStd) -> Result<Vec<u8>, SSSError> {
-    match encoding {
-        EncodingStd::Hex => secret_hex_to_bytes(s),
-        EncodingStd::Base64 => secret_base64_to_bytes(s),
+#[inline(always)]
+pub fn create_inlined(
+    min_shares_count: usize,
+    total_shares_count: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, errors::SSSError> {
+    if !is_proper_size(secret) {
+        return Err(errors::SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
     }
+    shamirss::create_shares(min_shares_count, total_shares_count, secret)
 }
 
-/// Encodes slice of shares bytes to slice of strings in given encoding standard.
+/// Creates shared secrets from given secret using custom prime for mod calculations.
+/// Prime shall be above 2^256 and below 2^257. Prime is not stored in the shares, shares can be
+/// combined only with combine_with_prime and the same prime, any other prime, or combine_std,
+/// gives a wrong secret without an error.
 ///
 /// # Argument
 ///
-/// * `b`  - vector of shares in bytes to encode.
+/// * `min`     - minimum shares amount needed to recreate the secret.
+/// * `total`   - total shares amount to create.
+/// * `secret`  - secret to be shared, divisible by 32 without rest.
+/// * `prime`   - prime for mod calculations.
 ///
 /// # Examples
 ///
 /// ```
-///use shamirss::{encode_shares_bytes, EncodingStd};
+///use openssl::bn::BigNum;
+///use shamirss::{combine_with_prime, create_with_prime};
 ///
-///let secret_shares: Vec<Vec<u8>> = vec![vec![1;128], vec![2;128], vec![3;128], vec![4;128]];
-///
-///
-///let encoding = EncodingStd::Hex;
-///let _enc = encode_shares_bytes(secret_shares, encoding);
+///let prime = BigNum::from_dec_str(
+///    "115792089237316195423570985008687907853269984665640564039457584007913129640237",
+///).unwrap();
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_with_prime(2, 3, &secret, &prime).unwrap();
+///assert_eq!(secret, combine_with_prime(secret_shares[1..].to_vec(), &prime).unwrap());
 ///```
 ///
-pub fn encode_shares_bytes(b: Vec<Vec<u8>>, encoding: EncodingStd) -> Vec<String> {
-    match encoding {
-        EncodingStd::Hex => shares_bytes_to_hex(b),
-        EncodingStd::Base64 => shares_bytes_to_base64(b),
+pub fn create_with_prime(
+    min: usize,
+    total: usize,
+    secret: &[u8],
+    prime: &openssl::bn::BigNum,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if !is_proper_size(secret) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
     }
+    let mut ctx = openssl::bn::BigNumContext::new()?;
+    shamirss::validate_custom_prime(&mut ctx, prime)?;
+    shamirss::create_shares_with_context(&mut ctx, &mut OpensslRng, min, total, secret, prime)
 }
 
-/// Decodes slice of shares strings encoded in given encoding standard to slice of shares in bytes.
+/// Combines shares created with create_with_prime using the same custom prime.
 ///
 /// # Argument
 ///
-/// * `b`  - vector of shares strings to decode.
+/// * `shares`  - vector of shares, equal or more the minimal share count.
+/// * `prime`   - prime the shares were created with.
+///
+pub fn combine_with_prime(
+    shares: Vec<Vec<u8>>,
+    prime: &openssl::bn::BigNum,
+) -> Result<Vec<u8>, SSSError> {
+    shamirss::combine_shares_with_prime(shares, prime)
+}
+
+/// Creates shared secrets from given secret pairing every share with a custodian label.
+/// Labels are not cryptographically bound to the shares, they only keep share to custodian
+/// mapping together.
+///
+/// # Argument
+///
+/// * `min`     - minimum shares amount needed to recreate the secret.
+/// * `total`   - total shares amount to create.
+/// * `secret`  - secret to be shared, divisible by 32 without rest.
+/// * `labels`  - custodian labels, one for every share.
 ///
 /// # Examples
 ///
 /// ```
-///use shamirss::{encode_shares_bytes, decode_shares_to_bytes, EncodingStd};
-///
-///let secret_shares: Vec<Vec<u8>> = vec![vec![1;128], vec![2;128], vec![3;128], vec![4;128]];
+///use shamirss::{combine_std, create_shares_labeled};
 ///
+///let secret = vec![7; 64];
+///let labels = ["alice".to_string(), "bob".to_string(), "carol".to_string()];
+///let labeled = create_shares_labeled(2, 3, &secret, &labels).unwrap();
+///assert_eq!(labeled[1].0, "bob");
+///let shares: Vec<Vec<u8>> = labeled.into_iter().map(|(_, share)| share).collect();
+///assert_eq!(secret, combine_std(shares[1..].to_vec()).unwrap());
+///```
 ///
-///let encoding = EncodingStd::Hex;
+pub fn create_shares_labeled(
+    min: usize,
+    total: usize,
+    secret: &[u8],
+    labels: &[String],
+) -> Result<Vec<(String, Vec<u8>)>, SSSError> {
+    if labels.len() != total {
+        return Err(SSSError::WithReason(format!(
+            "Labels count {} does not match total shares count {total}",
+            labels.len()
+        )));
+    }
+    let shares = create_std(min, total, secret)?;
+
+    Ok(labels.iter().cloned().zip(shares).collect())
+}
+
+/// Creates shared secrets from given secret of any size.
+/// Secret is prefixed with its 8 bytes big-endian length and zero padded to a multiple of 32,
+/// the length is shared together with the secret, so it cannot be altered on its own.
+/// Shares can be combined only with combine_padded.
+///
+/// # Argument
+///
+/// * `min_shares_count`    - minimum shares amount needed to recreate the secret.
+/// * `total_shares_count`  - total shares amount to create.
+/// * `secret`              - secret to be shared of any size.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_padded, create_padded};
+///
+///let secret = b"secret of any size";
+///let secret_shares: Vec<Vec<u8>> = create_padded(2, 3, secret).unwrap();
+///assert_eq!(secret.to_vec(), combine_padded(secret_shares[1..].to_vec()).unwrap());
+///```
+///
+pub fn create_padded(
+    min_shares_count: usize,
+    total_shares_count: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    let size = (PADDED_LENGTH_BYTES + secret.len()).div_ceil(CHUNK_BYTES) * CHUNK_BYTES;
+    let mut padded = Vec::with_capacity(size);
+    padded.extend((secret.len() as u64).to_be_bytes());
+    padded.extend(secret);
+    padded.resize(size, 0);
+    let shares = create_std(min_shares_count, total_shares_count, &padded);
+    wipe_bytes(&mut padded);
+    shares
+}
+
+/// Combines shares created with create_padded to the secret stripped from length and padding.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares, equal or more the minimal share count.
+///
+pub fn combine_padded(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    let mut padded = combine_std(shares)?;
+    let secret = strip_padding(&padded);
+    wipe_bytes(&mut padded);
+    secret
+}
+
+/// Strips the length header and zero padding added by create_padded.
+///
+#[inline(always)]
+fn strip_padding(padded: &[u8]) -> Result<Vec<u8>, SSSError> {
+    let invalid_header = || {
+        SSSError::WithReason(
+            "Recreated secret has no valid length header, shares are not padded shares.".to_owned(),
+        )
+    };
+    let header: [u8; PADDED_LENGTH_BYTES] = padded
+        .get(..PADDED_LENGTH_BYTES)
+        .and_then(|header| header.try_into().ok())
+        .ok_or_else(invalid_header)?;
+    let length = u64::from_be_bytes(header);
+    let content = padded.len() - PADDED_LENGTH_BYTES;
+    if length > content as u64 || content - length as usize >= CHUNK_BYTES {
+        return Err(invalid_header());
+    }
+    let length = length as usize;
+    let end = PADDED_LENGTH_BYTES + length;
+    if padded[end..].iter().any(|b| *b != 0) {
+        return Err(SSSError::WithReason(
+            "Recreated secret padding is not zeroed, shares are not padded shares.".to_owned(),
+        ));
+    }
+
+    Ok(padded[PADDED_LENGTH_BYTES..end].to_vec())
+}
+
+/// Creates shared secrets from given secret appending the 4 bytes big-endian CRC32 of every share.
+/// Checksum is a part of the share bytes, so it survives encoding and lets combine_std_checked
+/// point at the share mistyped while copied. Shares can be combined only with combine_std_checked.
+///
+/// # Argument
+///
+/// * `min_shares_count`    - minimum shares amount needed to recreate the secret.
+/// * `total_shares_count`  - total shares amount to create.
+/// * `secret`              - secret to be shared, divisible by 32 without rest.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std_checked, create_std_checked};
+///
+///let secret = vec![7; 64];
+///let mut secret_shares: Vec<Vec<u8>> = create_std_checked(2, 3, &secret).unwrap();
+///assert_eq!(secret, combine_std_checked(secret_shares[1..].to_vec()).unwrap());
+///secret_shares[2][10] ^= 1;
+///assert!(combine_std_checked(secret_shares[1..].to_vec()).is_err());
+///```
+///
+pub fn create_std_checked(
+    min_shares_count: usize,
+    total_shares_count: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    let mut shares = create_std(min_shares_count, total_shares_count, secret)?;
+    for share in shares.iter_mut() {
+        let checksum = crc32(share);
+        share.extend(checksum.to_be_bytes());
+    }
+
+    Ok(shares)
+}
+
+/// Combines shares created with create_std_checked to a secret.
+/// Every share checksum is verified and stripped before combining, the index of the first share
+/// with checksum mismatch is returned in the error.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares with checksum, equal or more the minimal share count.
+///
+pub fn combine_std_checked(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    combine_std(strip_checksums(&shares)?)
+}
+
+/// Checks whether share has the layout of shares created with create_std_checked,
+/// the known version byte and the body of whole share units followed by the checksum.
+///
+#[inline(always)]
+fn has_checksum_layout(share: &[u8]) -> bool {
+    share.len() > CHECKSUM_SIZE
+        && shamirss::share_body(share).is_err()
+        && shamirss::share_version(&share[..share.len() - CHECKSUM_SIZE])
+            .is_ok_and(|version| version != 0)
+}
+
+/// Verifies and strips the checksum of every share, the index of the first share with checksum
+/// mismatch is returned in the error.
+///
+fn strip_checksums(shares: &[Vec<u8>]) -> Result<Vec<Vec<u8>>, SSSError> {
+    let mut bodies = Vec::with_capacity(shares.len());
+    for (i, share) in shares.iter().enumerate() {
+        let body = share
+            .len()
+            .checked_sub(CHECKSUM_SIZE)
+            .map(|size| share.split_at(size))
+            .filter(|(body, checksum)| crc32(body).to_be_bytes() == **checksum)
+            .map(|(body, _)| body)
+            .ok_or_else(|| SSSError::WithReason(format!("share checksum mismatch at index {i}")))?;
+        bodies.push(body.to_vec());
+    }
+
+    Ok(bodies)
+}
+
+/// Creates shared secrets from given secret and overwrites the secret buffer with zeros.
+/// The buffer is zeroed before returning whether creating the shares succeeded or not,
+/// so the plaintext held by the caller is destroyed.
+///
+/// # Argument
+///
+/// * `min_shares_count`    - minimum shares amount needed to recreate the secret.
+/// * `total_shares_count`  - total shares amount to create.
+/// * `secret`              - mutable buffer with secret divisible by 32 without rest.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std, create_shares_shred};
+///
+///let mut secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_shares_shred(2, 3, &mut secret).unwrap();
+///assert_eq!(secret, vec![0; 64]);
+///assert_eq!(combine_std(secret_shares).unwrap(), vec![7; 64]);
+///```
+///
+pub fn create_shares_shred(
+    min_shares_count: usize,
+    total_shares_count: usize,
+    secret: &mut [u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    let shares = create_std(min_shares_count, total_shares_count, secret);
+    wipe_bytes(secret);
+    shares
+}
+
+/// Combines shares to a secrets.
+/// Function will be inlined.
+/// Accepts versioned shares as well as legacy shares created without the format version byte.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares to reconstruct the secret. Shall be equal or more the minimal
+///   share count required to re-create the secret used for crating shares.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_inlined, create_inlined};
+///
+///const SECRET_512_BYTES: &[u8; 512] = &[
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129, 160,
+///    99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96, 116,
+///    133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0, 29,
+///    136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129,
+///    160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96,
+///    116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0,
+///    29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93,
+///    129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173,
+///    202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0,
+///    0, 0, 0, 29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100,
+///    100, 93, 129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25,
+///    64, 173, 202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0,
+///    0, 0, 0, 0, 0, 0, 29, 136,
+///];
+///
+/// let secret_shares: Vec<Vec<u8>> = create_inlined(50, 100, SECRET_512_BYTES).unwrap();
+/// let secret_recreated = combine_inlined(secret_shares).unwrap();
+/// assert_eq!(SECRET_512_BYTES.to_vec(), secret_recreated);
+///```
+///
+#[inline(always)]
+pub fn combine_inlined(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, errors::SSSError> {
+    shamirss::combine_shares(shares)
+}
+
+/// Creates multi-secret shares from given secrets sharing the x-coordinates.
+/// Every custodian receives a single share holding points of all the secrets,
+/// secrets shall be of the same size divisible by 32 without rest.
+/// Share starts with the share format version byte and the secrets count byte.
+/// Multi-secret shares can be combined only with combine_shares_multisecret.
+///
+/// # Argument
+///
+/// * `min`      - minimum shares amount needed to recreate the secrets.
+/// * `total`    - total shares amount to create.
+/// * `secrets`  - slice of secrets to be shared, up to 255 secrets.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_shares_multisecret, create_shares_multisecret};
+///
+///let keys: [&[u8]; 2] = [&[1; 32], &[2; 32]];
+///let secret_shares: Vec<Vec<u8>> = create_shares_multisecret(3, 5, &keys).unwrap();
+///let keys_recreated = combine_shares_multisecret(secret_shares[..3].to_vec()).unwrap();
+///assert_eq!(keys_recreated, vec![vec![1; 32], vec![2; 32]]);
+///```
+///
+pub fn create_shares_multisecret(
+    min: usize,
+    total: usize,
+    secrets: &[&[u8]],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if let Some(secret) = secrets.iter().find(|secret| !is_proper_size(secret)) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
+    }
+    shamirss::create_shares_multisecret(min, total, secrets)
+}
+
+/// Combines multi-secret shares to the secrets in the order they were shared.
+///
+/// # Argument
+///
+/// * `shares`  - vector of multi-secret shares, equal or more the minimal share count.
+///
+pub fn combine_shares_multisecret(shares: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, SSSError> {
+    shamirss::combine_shares_multisecret(shares)
+}
+
+/// Combines shares holding different chunks count to the secret prefix covered by all of them.
+/// Custodian may keep only the first chunks of the share, the secret is then recreated up to
+/// the shortest share. Unlike combine_std the shares do not need to be of equal size.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares or share prefixes, equal or more the minimal share count.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_prefix, create_std, SHARE_HEADER_BYTES, SHARE_UNIT_BYTES};
+///
+///let secret = vec![7; 128];
+///let mut secret_shares: Vec<Vec<u8>> = create_std(2, 3, &secret).unwrap();
+///secret_shares[0].truncate(SHARE_HEADER_BYTES + 2 * SHARE_UNIT_BYTES);
+///let prefix = combine_prefix(secret_shares[..2].to_vec()).unwrap();
+///assert_eq!(secret[..64].to_vec(), prefix);
+///```
+///
+pub fn combine_prefix(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    shamirss::combine_shares_prefix(shares)
+}
+
+/// Creates shares from 256-bit BIP39 entropy backing a 24-word mnemonic.
+///
+/// # Argument
+///
+/// * `entropy` - 32 bytes of BIP39 entropy.
+/// * `min`     - minimum shares amount to recreate the entropy.
+/// * `total`   - total shares amount to create.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_bip39_entropy, split_bip39_entropy};
+///
+///let entropy = [42; 32];
+///let secret_shares: Vec<Vec<u8>> = split_bip39_entropy(&entropy, 2, 3).unwrap();
+///assert_eq!(entropy, combine_bip39_entropy(secret_shares[1..].to_vec()).unwrap());
+///```
+///
+pub fn split_bip39_entropy(
+    entropy: &[u8; BIP39_ENTROPY_BYTES],
+    min: usize,
+    total: usize,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    create_std(min, total, entropy)
+}
+
+/// Combines shares created with split_bip39_entropy back to the 256-bit BIP39 entropy.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares, equal or more the minimal share count.
+///
+pub fn combine_bip39_entropy(shares: Vec<Vec<u8>>) -> Result<[u8; BIP39_ENTROPY_BYTES], SSSError> {
+    let mut secret = combine_std(shares)?;
+    if secret.len() != BIP39_ENTROPY_BYTES {
+        let len = secret.len();
+        wipe_bytes(&mut secret);
+        return Err(SSSError::WithReason(format!(
+            "Shares hold {len} bytes secret, BIP39 entropy shall be {BIP39_ENTROPY_BYTES} bytes"
+        )));
+    }
+    let mut entropy = [0u8; BIP39_ENTROPY_BYTES];
+    entropy.copy_from_slice(&secret);
+    wipe_bytes(&mut secret);
+
+    Ok(entropy)
+}
+
+/// Repairs a corrupted share from the shares of other custodians.
+/// Header and x-coordinates of the damaged share have to be intact, the y-coordinates are
+/// re-derived from the polynomials recovered from the good shares, so the original share is
+/// restored.
+///
+/// # Argument
+///
+/// * `good_shares`  - vector of intact shares, equal or more the minimal share count.
+/// * `damaged`      - share with corrupted y-coordinates to repair.
+/// * `min`          - minimal share count the shares were created with.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, repair_share};
+///
+///let secret_shares: Vec<Vec<u8>> = create_std(2, 3, &[7; 64]).unwrap();
+///let mut damaged = secret_shares[2].clone();
+///damaged[40] ^= 0xff;
+///let repaired = repair_share(secret_shares[..2].to_vec(), &damaged, 2).unwrap();
+///assert_eq!(repaired, secret_shares[2]);
+///```
+///
+pub fn repair_share(
+    good_shares: Vec<Vec<u8>>,
+    damaged: &[u8],
+    min: usize,
+) -> Result<Vec<u8>, SSSError> {
+    shamirss::repair_share(good_shares, damaged, min)
+}
+
+/// Re-randomizes shares keeping the secret, so shares leaked before the refresh become useless.
+/// As many new shares as given are returned, they combine to the same secret but do not combine
+/// together with the old shares.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares, equal or more the minimal share count.
+/// * `min`     - minimal share count the shares were created with.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std, create_std, refresh_shares};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_std(2, 3, &secret).unwrap();
+///let refreshed = refresh_shares(secret_shares.clone(), 2).unwrap();
+///assert_eq!(secret, combine_std(refreshed[1..].to_vec()).unwrap());
+///assert_ne!(secret, combine_std(vec![secret_shares[0].clone(), refreshed[1].clone()]).unwrap());
+///```
+///
+pub fn refresh_shares(shares: Vec<Vec<u8>>, min: usize) -> Result<Vec<Vec<u8>>, SSSError> {
+    shamirss::refresh_shares(shares, min)
+}
+
+/// Creates shared secrets from given secret creating shares in parallel.
+/// Available with the `rayon` feature, speeds up creating many shares of large secrets.
+/// Shares are returned in order and combine as shares created with create_std.
+///
+/// # Argument
+///
+/// * `min_shares_count`     - minimum shares amount needed to recreate the secret.
+/// * `total_shares_count`   - total shares amount to create.
+/// * `secret`               - secret to be shared, divisible by 32 without rest.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std, create_parallel};
+///
+///let secret = vec![7; 512];
+///let secret_shares: Vec<Vec<u8>> = create_parallel(10, 20, &secret).unwrap();
+///let secret_recreated = combine_std(secret_shares[5..15].to_vec()).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+#[cfg(feature = "rayon")]
+pub fn create_parallel(
+    min_shares_count: usize,
+    total_shares_count: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, errors::SSSError> {
+    if !is_proper_size(secret) {
+        return Err(errors::SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
+    }
+    shamirss::create_shares_parallel(min_shares_count, total_shares_count, secret)
+}
+
+/// Combines shares to a secrets interpolating secret chunks in parallel.
+/// Available with the `rayon` feature, speeds up combining of large secrets.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares to reconstruct the secret. Shall be equal or more the minimal
+///   share count required to re-create the secret used for crating shares.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_parallel, create_std};
+///
+///let secret = vec![7; 512];
+///let secret_shares: Vec<Vec<u8>> = create_std(10, 20, &secret).unwrap();
+///let secret_recreated = combine_parallel(secret_shares).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+#[cfg(feature = "rayon")]
+pub fn combine_parallel(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, errors::SSSError> {
+    shamirss::combine_shares_parallel(shares)
+}
+
+/// Combines shares given as rows of a matrix to a secret.
+/// Available with the `ndarray` feature.
+///
+/// # Argument
+///
+/// * `shares`  - matrix view where each row is a share. Rows count shall be equal or more the
+///   minimal share count required to re-create the secret used for crating shares.
+///
+/// # Examples
+///
+/// ```
+///use ndarray::Array2;
+///use shamirss::{combine_shares_ndarray, create_std};
+///
+///let secret = vec![7; 128];
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///let matrix = Array2::from_shape_vec((5, secret_shares[0].len()), secret_shares.concat()).unwrap();
+///let secret_recreated = combine_shares_ndarray(matrix.view()).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+#[cfg(feature = "ndarray")]
+pub fn combine_shares_ndarray(shares: ndarray::ArrayView2<u8>) -> Result<Vec<u8>, SSSError> {
+    let shares: Vec<Vec<u8>> = shares.rows().into_iter().map(|r| r.to_vec()).collect();
+    shamirss::combine_shares(shares)
+}
+
+/// Combines shares to a secret wrapped in `Zeroizing`, so the secret is wiped on drop.
+/// Available with the `zeroize` feature.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares to reconstruct the secret. Shall be equal or more the minimal
+///   share count required to re-create the secret used for crating shares.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_shares_zeroizing, create_std};
+///
+///let secret = vec![7; 128];
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///let secret_recreated = combine_shares_zeroizing(secret_shares).unwrap();
+///assert_eq!(secret, *secret_recreated);
+///```
+///
+#[cfg(feature = "zeroize")]
+pub fn combine_shares_zeroizing(
+    shares: Vec<Vec<u8>>,
+) -> Result<zeroize::Zeroizing<Vec<u8>>, errors::SSSError> {
+    Ok(zeroize::Zeroizing::new(shamirss::combine_shares(shares)?))
+}
+
+/// Recreates secret from the first `min` shares and verifies the remaining shares against it.
+/// Every held-out share has to lie on the recreated chunk polynomials, which detects corrupted
+/// shares when more than `min` shares are available.
+///
+/// # Argument
+///
+/// * `shares`  - vector of at least `min` + 1 shares.
+/// * `min`     - minimum shares amount needed to recreate the secret.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_and_verify, create_std};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///let secret_recreated = combine_and_verify(secret_shares, 3).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+pub fn combine_and_verify(shares: Vec<Vec<u8>>, min: usize) -> Result<Vec<u8>, SSSError> {
+    shamirss::combine_shares_verified(shares, min)
+}
+
+/// Recreates secret from every subset of `min` shares and returns it only if all subsets agree.
+/// Calculates C(n, min) combines, so it is slow and meant for auditing a set of shares.
+/// Fails listing the disagreeing subsets by share indexes when any subset recreates other secret.
+///
+/// # Argument
+///
+/// * `shares`       - slice of shares to audit.
+/// * `min`          - minimum shares amount needed to recreate the secret.
+/// * `max_subsets`  - maximum number of subsets allowed to be combined.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, try_combine_all_subsets};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///let secret_recreated = try_combine_all_subsets(&secret_shares, 3, 10).unwrap();
+///assert_eq!(secret, secret_recreated);
+///assert!(try_combine_all_subsets(&secret_shares, 3, 9).is_err());
+///```
+///
+pub fn try_combine_all_subsets(
+    shares: &[Vec<u8>],
+    min: usize,
+    max_subsets: usize,
+) -> Result<Vec<u8>, SSSError> {
+    shamirss::combine_all_subsets(shares, min, max_subsets, None)
+}
+
+/// Recreates secret as try_combine_all_subsets does, giving up once the deadline passes.
+/// Bounds the work of auditing large sets of shares, for example in a request handler.
+///
+/// # Argument
+///
+/// * `shares`       - slice of shares to audit.
+/// * `min`          - minimum shares amount needed to recreate the secret.
+/// * `max_subsets`  - maximum number of subsets allowed to be combined.
+/// * `deadline`     - instant after which the reconstruction fails as timed out.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, try_combine_all_subsets_until};
+///use std::time::{Duration, Instant};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///let deadline = Instant::now() + Duration::from_secs(10);
+///let secret_recreated = try_combine_all_subsets_until(&secret_shares, 3, 10, deadline).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+pub fn try_combine_all_subsets_until(
+    shares: &[Vec<u8>],
+    min: usize,
+    max_subsets: usize,
+    deadline: std::time::Instant,
+) -> Result<Vec<u8>, SSSError> {
+    shamirss::combine_all_subsets(shares, min, max_subsets, Some(deadline))
+}
+
+/// Reads shares from given reader and combines them to a secret.
+/// Every share is framed as 4 bytes big-endian length followed by the share bytes,
+/// frames are read until EOF.
+///
+/// # Argument
+///
+/// * `reader`  - reader yielding length-prefixed shares.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_shares_from_reader, create_std};
+///
+///let secret = vec![7; 64];
+///let mut framed = Vec::new();
+///for share in create_std(2, 3, &secret).unwrap() {
+///    framed.extend((share.len() as u32).to_be_bytes());
+///    framed.extend(share);
+///}
+///let secret_recreated = combine_shares_from_reader(std::io::Cursor::new(framed)).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+pub fn combine_shares_from_reader<R: std::io::Read>(reader: R) -> Result<Vec<u8>, SSSError> {
+    let shares = read_framed_shares(reader)?;
+    for share in shares.iter() {
+        Share::from_bytes(share)?;
+    }
+    shamirss::combine_shares(shares)
+}
+
+/// Layout of the shares the secret was recreated from, reported by combine_detailed.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareLayout {
+    /// Legacy shares without the share format version byte.
+    Legacy,
+    /// Shares starting with the share format version byte, legacy shares may be mixed in.
+    Versioned,
+    /// Shares created with create_shares_with_header embedding the minimum shares count.
+    ThresholdHeader,
+    /// Shares created with create_std_checked carrying the checksum, whatever the header.
+    Checksummed,
+}
+
+/// Report of the secret reconstruction returned by combine_detailed.
+///
+#[derive(Debug, Clone)]
+pub struct Reconstruction {
+    pub secret: Vec<u8>,
+    /// Layout of the shares, the checksum takes precedence over the header.
+    pub layout: ShareLayout,
+    /// Distinct shares the secret was recreated from, duplicates are counted once.
+    pub shares_used: usize,
+    /// Minimum shares count embedded in the shares created with create_shares_with_header,
+    /// other shares do not embed it.
+    pub threshold: Option<usize>,
+    /// Shares starting with the share format version byte.
+    pub versioned_shares: usize,
+    /// Legacy shares created without the share format version byte.
+    pub legacy_shares: usize,
+    /// Whether the checksum of every share passed, only shares created with create_std_checked
+    /// carry the checksum.
+    pub integrity_verified: bool,
+}
+
+/// Combines shares to a secret returning the reconstruction report.
+/// Works as combine_std, the report describes the shares the secret was recreated from.
+/// Checksummed shares are verified and stripped before combining, shares with the threshold header
+/// are combined as combine_checked does.
+///
+/// # Argument
+///
+/// * `shares`  - vector of shares to reconstruct the secret.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_detailed, create_std};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///let reconstruction = combine_detailed(secret_shares[..3].to_vec()).unwrap();
+///assert_eq!(secret, reconstruction.secret);
+///assert_eq!(reconstruction.shares_used, 3);
+///```
+///
+pub fn combine_detailed(shares: Vec<Vec<u8>>) -> Result<Reconstruction, SSSError> {
+    shamirss::validate_shares_provided(&shares)?;
+    let mut integrity_verified = false;
+    let shares = if shares.iter().all(|share| has_checksum_layout(share)) {
+        let bodies = strip_checksums(&shares)?;
+        integrity_verified = true;
+        bodies
+    } else {
+        shares
+    };
+
+    let mut versioned_shares = 0;
+    let mut threshold_shares = 0;
+    let mut xs = std::collections::HashSet::with_capacity(shares.len());
+    for share in shares.iter() {
+        let body = shamirss::share_body(share)?;
+        if body.len() != share.len() {
+            versioned_shares += 1;
+        }
+        if shamirss::share_version(share)? == shamirss::THRESHOLD_FORMAT_VERSION {
+            threshold_shares += 1;
+        }
+        xs.insert(&body[..CHUNK_BYTES]);
+    }
+    let shares_used = xs.len();
+    let legacy_shares = shares.len() - versioned_shares;
+    let threshold = (threshold_shares == shares.len()).then(|| shares[0][1] as usize);
+    let layout = if integrity_verified {
+        ShareLayout::Checksummed
+    } else if threshold.is_some() {
+        ShareLayout::ThresholdHeader
+    } else if versioned_shares == 0 {
+        ShareLayout::Legacy
+    } else {
+        ShareLayout::Versioned
+    };
+    let secret = match threshold {
+        Some(_) => shamirss::combine_shares_checked(shares)?,
+        None => shamirss::combine_shares(shares)?,
+    };
+
+    Ok(Reconstruction {
+        secret,
+        layout,
+        shares_used,
+        threshold,
+        versioned_shares,
+        legacy_shares,
+        integrity_verified,
+    })
+}
+
+/// Checks if given bytes are plausibly a share without attempting reconstruction.
+/// Bytes shall hold at least one share unit and be aligned to the share unit size,
+/// versioned shares shall have a supported format version byte.
+///
+/// # Argument
+///
+/// * `bytes`  - bytes to check.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, looks_like_share};
+///
+///let secret_shares: Vec<Vec<u8>> = create_std(2, 3, &[7; 64]).unwrap();
+///assert!(looks_like_share(&secret_shares[0]));
+///assert!(!looks_like_share(&[]));
+///```
+///
+pub fn looks_like_share(bytes: &[u8]) -> bool {
+    shamirss::share_body(bytes).is_ok()
+}
+
+/// Encoding standard for secret and shares.
+///
+#[derive(Debug, Clone)]
+pub enum EncodingStd {
+    Hex,
+    /// Uppercase hex, decoding accepts any case as Hex does.
+    HexUpper,
+    Base64,
+    /// URL and filename safe base64 without padding, decoding rejects the standard alphabet.
+    Base64UrlSafe,
+    /// Padded base64 with custom alphabet, use EncodingStd::base64_custom to create it.
+    Base64Custom(Box<base64::alphabet::Alphabet>),
+    /// Bitcoin alphabet base58, without characters that are easy to confuse when transcribing.
+    Base58,
+    /// RFC 4648 base32 padded with '=', decoding accepts lowercase letters and missing padding.
+    Base32,
+    /// RFC 4648 base32 without padding, decoding accepts lowercase letters.
+    Base32NoPad,
+}
+
+impl EncodingStd {
+    /// Creates padded base64 encoding standard with given custom alphabet.
+    /// Alphabet shall hold 64 unique printable ASCII characters other than '='.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///use shamirss::{decode_secret_to_bytes, encode_secret_bytes, EncodingStd};
+    ///
+    ///let alphabet = "ZYXWVUTSRQPONMLKJIHGFEDCBAzyxwvutsrqponmlkjihgfedcba9876543210+/";
+    ///let encoding = EncodingStd::base64_custom(alphabet).unwrap();
+    ///let enc = encode_secret_bytes(&[1, 2, 3], encoding.clone());
+    ///assert_eq!(decode_secret_to_bytes(&enc, encoding).unwrap(), vec![1, 2, 3]);
+    ///assert!(EncodingStd::base64_custom("too short").is_err());
+    ///```
+    ///
+    pub fn base64_custom(alphabet: &str) -> Result<Self, SSSError> {
+        let alphabet = base64::alphabet::Alphabet::new(alphabet)
+            .map_err(|e| SSSError::WithReason(format!("Invalid base64 alphabet: {e}")))?;
+        Ok(Self::Base64Custom(Box::new(alphabet)))
+    }
+}
+
+/// Encodes secret bytes to string in given encoding standard.
+///
+/// # Argument
+///
+/// * `b`  - secret bytes to encode.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{encode_secret_bytes, EncodingStd};
+///
+///const secret_bytes: &[u8; 512] = &[
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129, 160,
+///    99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96, 116,
+///    133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0, 29,
+///    136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129,
+///    160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96,
+///    116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0,
+///    29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93,
+///    129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173,
+///    202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0,
+///    0, 0, 0, 29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100,
+///    100, 93, 129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25,
+///    64, 173, 202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0,
+///    0, 0, 0, 0, 0, 0, 29, 136,
+///];
+///
+///let encoding = EncodingStd::Hex;
+///let enc = encode_secret_bytes(secret_bytes, encoding);
+///```
+///
+pub fn encode_secret_bytes(b: &[u8], encoding: EncodingStd) -> String {
+    match encoding {
+        EncodingStd::Hex => secret_bytes_to_hex(b, false),
+        EncodingStd::HexUpper => secret_bytes_to_hex(b, true),
+        EncodingStd::Base64 => secret_bytes_to_base64(b),
+        EncodingStd::Base64UrlSafe => secret_bytes_to_base64_with(b, &URL_SAFE_BASE64),
+        EncodingStd::Base64Custom(alphabet) => {
+            secret_bytes_to_base64_with(b, &custom_base64(&alphabet))
+        }
+        EncodingStd::Base58 => secret_bytes_to_base58(b),
+        EncodingStd::Base32 => secret_bytes_to_base32(b, true),
+        EncodingStd::Base32NoPad => secret_bytes_to_base32(b, false),
+    }
+}
+
+/// Decodes secret to bytes from string in given encoding standard.
+///
+/// # Argument
+///
+/// * `s`  - secret string to decode.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{encode_secret_bytes, decode_secret_to_bytes, EncodingStd};
+///
+///const secret_bytes: &[u8; 512] = &[
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129, 160,
+///    99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96, 116,
+///    133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0, 29,
+///    136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93, 129,
+///    160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173, 202, 96,
+///    116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0, 0, 0, 0,
+///    29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100, 100, 93,
+///    129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25, 64, 173,
+///    202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0, 0, 0, 0,
+///    0, 0, 0, 29, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 110, 110, 110, 110, 110, 110, 110, 110, 110, 110, 112, 99, 131, 144, 100,
+///    100, 93, 129, 160, 99, 153, 151, 145, 114, 122, 123, 127, 148, 120, 98, 137, 175, 0, 0, 0, 0,
+///    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 25,
+///    64, 173, 202, 96, 116, 133, 114, 12, 186, 37, 43, 2, 70, 194, 52, 40, 80, 160, 233, 205, 0, 0,
+///    0, 0, 0, 0, 0, 0, 29, 136,
+///];
+///
+///let encoding = EncodingStd::Hex;
+///let enc = encode_secret_bytes(secret_bytes, encoding.clone());
+///let _dec = decode_secret_to_bytes(&enc, encoding).unwrap();
+///```
+///
+pub fn decode_secret_to_bytes(s: &str, encoding: EncodingStd) -> Result<Vec<u8>, SSSError> {
+    match encoding {
+        EncodingStd::Hex | EncodingStd::HexUpper => secret_hex_to_bytes(s),
+        EncodingStd::Base64 => secret_base64_to_bytes(s),
+        EncodingStd::Base64UrlSafe => secret_base64_to_bytes_with(s, &URL_SAFE_BASE64),
+        EncodingStd::Base64Custom(alphabet) => {
+            secret_base64_to_bytes_with(s, &custom_base64(&alphabet))
+        }
+        EncodingStd::Base58 => secret_base58_to_bytes(s),
+        EncodingStd::Base32 | EncodingStd::Base32NoPad => secret_base32_to_bytes(s),
+    }
+}
+
+/// Encodes bytes to lowercase hex split to groups of given hex digits count, e.g. for printed
+/// backup cards. Groups are joined with given separator, group length 0 gives ungrouped hex.
+/// Hex decoding skips whitespace and '-' or ':' separators, so such grouped hex decodes as is.
+///
+/// # Argument
+///
+/// * `bytes`      - bytes to encode.
+/// * `group_len`  - hex digits count in a group.
+/// * `separator`  - separator between groups.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{decode_secret_to_bytes, hex_grouped, EncodingStd};
+///
+///let grouped = hex_grouped(&[0xab, 0xcd, 0xef], 4, " ");
+///assert_eq!(grouped, "abcd ef");
+///assert_eq!(decode_secret_to_bytes(&grouped, EncodingStd::Hex).unwrap(), vec![0xab, 0xcd, 0xef]);
+///```
+///
+pub fn hex_grouped(bytes: &[u8], group_len: usize, separator: &str) -> String {
+    bytes_to_hex_grouped(bytes, group_len, separator)
+}
+
+/// Encodes slice of shares bytes to slice of strings in given encoding standard.
+///
+/// # Argument
+///
+/// * `b`  - vector of shares in bytes to encode.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{encode_shares_bytes, EncodingStd};
+///
+///let secret_shares: Vec<Vec<u8>> = vec![vec![1;128], vec![2;128], vec![3;128], vec![4;128]];
+///
+///
+///let encoding = EncodingStd::Hex;
+///let _enc = encode_shares_bytes(secret_shares, encoding);
+///```
+///
+pub fn encode_shares_bytes(b: Vec<Vec<u8>>, encoding: EncodingStd) -> Vec<String> {
+    match encoding {
+        EncodingStd::Hex => shares_bytes_to_hex(b, false),
+        EncodingStd::HexUpper => shares_bytes_to_hex(b, true),
+        EncodingStd::Base64 => shares_bytes_to_base64(b),
+        EncodingStd::Base64UrlSafe => shares_bytes_to_base64_with(b, &URL_SAFE_BASE64),
+        EncodingStd::Base64Custom(alphabet) => {
+            shares_bytes_to_base64_with(b, &custom_base64(&alphabet))
+        }
+        EncodingStd::Base58 => shares_bytes_to_base58(b),
+        EncodingStd::Base32 => shares_bytes_to_base32(b, true),
+        EncodingStd::Base32NoPad => shares_bytes_to_base32(b, false),
+    }
+}
+
+/// Encodes shares in given encoding standard prefixing each one with a label comment line.
+/// Label has the form `# shamir v{version} {min}/{total} idx={index}` with index starting at 1,
+/// and is skipped by decode_shares_to_bytes. Version is the format version of the labeled share,
+/// legacy shares without the version byte are labeled as version 0.
+///
+/// # Argument
+///
+/// * `shares`    - slice of shares to label.
+/// * `min`       - minimum shares amount needed to recreate the secret.
+/// * `total`     - total shares amount created.
+/// * `encoding`  - encoding standard to encode the shares in.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, decode_shares_to_bytes, label_shares, EncodingStd};
+///
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &[7; 64]).unwrap();
+///let labeled = label_shares(&secret_shares, 3, 5, EncodingStd::Hex);
+///assert!(labeled[2].starts_with("# shamir v2 3/5 idx=3\n"));
+///assert_eq!(secret_shares, decode_shares_to_bytes(&labeled, EncodingStd::Hex).unwrap());
+///```
+///
+pub fn label_shares(
+    shares: &[Vec<u8>],
+    min: usize,
+    total: usize,
+    encoding: EncodingStd,
+) -> Vec<String> {
+    shares
+        .iter()
+        .enumerate()
+        .map(|(i, share)| {
+            format!(
+                "# shamir v{} {min}/{total} idx={}\n{}",
+                shamirss::share_version(share).unwrap_or(shamirss::SHARE_FORMAT_VERSION),
+                i + 1,
+                encode_secret_bytes(share, encoding.clone())
+            )
+        })
+        .collect()
+}
+
+/// Combines labeled shares, as created by label_shares, to a secret.
+/// Labels are cross-checked against each other and against the share format version byte,
+/// shares count is checked against the labeled minimum before combining.
+///
+/// # Argument
+///
+/// * `shares`    - slice of labeled shares strings.
+/// * `encoding`  - encoding standard the shares are encoded in.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_labeled_shares, create_std, label_shares, EncodingStd};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///let labeled = label_shares(&secret_shares, 3, 5, EncodingStd::Base64);
+///assert_eq!(secret, combine_labeled_shares(&labeled[1..4], EncodingStd::Base64).unwrap());
+///assert!(combine_labeled_shares(&labeled[1..3], EncodingStd::Base64).is_err());
+///```
+///
+pub fn combine_labeled_shares(
+    shares: &[String],
+    encoding: EncodingStd,
+) -> Result<Vec<u8>, SSSError> {
+    combine_labeled(shares, encoding, None)
+}
+
+/// Combines labeled shares as combine_labeled_shares does, rejecting shares labeled with
+/// a threshold other than the expected one, which detects mixed or tampered sets.
+///
+/// # Argument
+///
+/// * `shares`    - slice of labeled shares strings.
+/// * `encoding`  - encoding standard the shares are encoded in.
+/// * `expected`  - threshold the shares were created with.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{
+///    combine_labeled_shares_expect_threshold, create_std, label_shares, EncodingStd, Threshold,
+///};
+///
+///let secret = vec![7; 64];
+///let secret_shares: Vec<Vec<u8>> = create_std(3, 5, &secret).unwrap();
+///let labeled = label_shares(&secret_shares, 3, 5, EncodingStd::Hex);
+///let expected = Threshold::new(3, 5).unwrap();
+///let secret_recreated =
+///    combine_labeled_shares_expect_threshold(&labeled, EncodingStd::Hex, expected).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+pub fn combine_labeled_shares_expect_threshold(
+    shares: &[String],
+    encoding: EncodingStd,
+    expected: Threshold,
+) -> Result<Vec<u8>, SSSError> {
+    combine_labeled(shares, encoding, Some(expected))
+}
+
+/// Cross-checks share labels, optionally against the expected threshold, and combines shares.
+///
+fn combine_labeled(
+    shares: &[String],
+    encoding: EncodingStd,
+    expected: Option<Threshold>,
+) -> Result<Vec<u8>, SSSError> {
+    let decoded = decode_shares_to_bytes(shares, encoding)?;
+    let mut threshold: Option<(usize, usize)> = None;
+    let mut indexes = Vec::with_capacity(shares.len());
+    for (i, (share, bytes)) in shares.iter().zip(decoded.iter()).enumerate() {
+        let label = parse_share_label(share)?;
+        let version = shamirss::share_version(bytes)?;
+        if label.version != version {
+            return Err(SSSError::WithReason(format!(
+                "Share at index {i} is labeled as version {}, but has format version {version}.",
+                label.version
+            )));
+        }
+        if let Some(expected) = expected {
+            if (label.min, label.total) != (expected.min(), expected.total()) {
+                return Err(SSSError::WithReason(format!(
+                    "Share at index {i} is labeled with threshold {}/{}, expected {}/{}.",
+                    label.min,
+                    label.total,
+                    expected.min(),
+                    expected.total()
+                )));
+            }
+        }
+        if *threshold.get_or_insert((label.min, label.total)) != (label.min, label.total) {
+            return Err(SSSError::WithReason(format!(
+                "Share at index {i} is labeled with threshold {}/{}, other shares differ.",
+                label.min, label.total
+            )));
+        }
+        if label.index == 0 || label.index > label.total || indexes.contains(&label.index) {
+            return Err(SSSError::WithReason(format!(
+                "Share at index {i} has invalid or repeated label index {}.",
+                label.index
+            )));
+        }
+        indexes.push(label.index);
+    }
+    let (min, _) = threshold.unwrap_or_default();
+    if decoded.len() < min {
+        return Err(SSSError::WithReason(format!(
+            "Labels require at least {min} shares to combine, got {}.",
+            decoded.len()
+        )));
+    }
+
+    shamirss::combine_shares(decoded)
+}
+
+/// Shares decoded from pasted lines with the counts of lines dropped on the way.
+///
+#[derive(Debug, Clone, Default)]
+pub struct SanitizedShares {
+    pub shares: Vec<Vec<u8>>,
+    pub blanks: usize,
+    pub comments: usize,
+    pub duplicates: usize,
+}
+
+/// Sanitizes pasted share lines encoded in given encoding standard.
+/// Lines are trimmed, blank and comment lines are dropped, and exact duplicate shares are removed
+/// keeping the first occurrence.
+///
+/// # Argument
+///
+/// * `lines`     - slice of lines holding one encoded share each.
+/// * `encoding`  - encoding standard the shares are encoded in.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, encode_shares_bytes, sanitize_shares, EncodingStd};
+///
+///let secret_shares: Vec<Vec<u8>> = create_std(2, 3, &[7; 64]).unwrap();
+///let mut lines = encode_shares_bytes(secret_shares.clone(), EncodingStd::Hex);
+///lines.push(format!("  {}  ", lines[0]));
+///lines.push(String::new());
+///let sanitized = sanitize_shares(&lines, EncodingStd::Hex).unwrap();
+///assert_eq!(sanitized.shares, secret_shares);
+///assert_eq!((sanitized.blanks, sanitized.duplicates), (1, 1));
+///```
+///
+pub fn sanitize_shares(
+    lines: &[String],
+    encoding: EncodingStd,
+) -> Result<SanitizedShares, SSSError> {
+    let mut sanitized = SanitizedShares::default();
+    for (i, line) in lines.iter().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            sanitized.blanks += 1;
+            continue;
+        }
+        if line.starts_with('#') {
+            sanitized.comments += 1;
+            continue;
+        }
+        let share = decode_secret_to_bytes(line, encoding.clone()).map_err(|e| {
+            SSSError::WithReason(format!("Share at line {i} failed to decode: {e}"))
+        })?;
+        if sanitized.shares.contains(&share) {
+            sanitized.duplicates += 1;
+            continue;
+        }
+        sanitized.shares.push(share);
+    }
+
+    Ok(sanitized)
+}
+
+/// Sanitizes pasted share lines as sanitize_shares does and combines distinct shares to a secret.
+///
+/// # Argument
+///
+/// * `lines`     - slice of lines holding one encoded share each.
+/// * `encoding`  - encoding standard the shares are encoded in.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, encode_shares_bytes, sanitize_and_combine, EncodingStd};
+///
+///let secret = vec![7; 64];
+///let mut lines = encode_shares_bytes(create_std(2, 3, &secret).unwrap(), EncodingStd::Base64);
+///lines.insert(1, lines[0].clone());
+///assert_eq!(secret, sanitize_and_combine(&lines, EncodingStd::Base64).unwrap());
+///```
+///
+pub fn sanitize_and_combine(lines: &[String], encoding: EncodingStd) -> Result<Vec<u8>, SSSError> {
+    let sanitized = sanitize_shares(lines, encoding)?;
+    if sanitized.shares.len() < shamirss::MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "At least {} distinct shares are required to combine, got {} after removing {} duplicates.",
+            shamirss::MIN_SHARES_COUNT,
+            sanitized.shares.len(),
+            sanitized.duplicates
+        )));
+    }
+
+    shamirss::combine_shares(sanitized.shares)
+}
+
+/// Reads shares line by line from given reader until EOF and combines them to a secret.
+/// Lines are sanitized as sanitize_shares does, so blank, comment and duplicate lines are skipped.
+///
+/// # Argument
+///
+/// * `reader`    - buffered reader yielding one encoded share per line.
+/// * `encoding`  - encoding standard the shares are encoded in.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_from_lines, create_std, encode_shares_bytes, EncodingStd};
+///
+///let secret = vec![7; 64];
+///let lines = encode_shares_bytes(create_std(2, 3, &secret).unwrap(), EncodingStd::Base64);
+///let input = lines.join("\n");
+///let secret_recreated = combine_from_lines(input.as_bytes(), EncodingStd::Base64).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+pub fn combine_from_lines<R: std::io::BufRead>(
+    reader: R,
+    encoding: EncodingStd,
+) -> Result<Vec<u8>, SSSError> {
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    sanitize_and_combine(&lines, encoding)
+}
+
+/// Decodes slice of shares strings encoded in given encoding standard to slice of shares in bytes.
+/// Label comment lines starting with '#', as added by label_shares, are skipped.
+///
+/// # Argument
+///
+/// * `b`  - vector of shares strings to decode.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{encode_shares_bytes, decode_shares_to_bytes, EncodingStd};
+///
+///let secret_shares: Vec<Vec<u8>> = vec![vec![1;128], vec![2;128], vec![3;128], vec![4;128]];
+///
+///
+///let encoding = EncodingStd::Hex;
 ///let enc = encode_shares_bytes(secret_shares, encoding.clone());
 ///let _dec = decode_shares_to_bytes(&enc, encoding).unwrap();
 ///```
@@ -365,7 +1888,908 @@ pub fn decode_shares_to_bytes(
     encoding: EncodingStd,
 ) -> Result<Vec<Vec<u8>>, SSSError> {
     match encoding {
-        EncodingStd::Hex => shares_hex_to_bytes(s),
+        EncodingStd::Hex | EncodingStd::HexUpper => shares_hex_to_bytes(s),
         EncodingStd::Base64 => shares_base64_to_bytes(s),
+        EncodingStd::Base64UrlSafe => shares_base64_to_bytes_with(s, &URL_SAFE_BASE64),
+        EncodingStd::Base64Custom(alphabet) => {
+            shares_base64_to_bytes_with(s, &custom_base64(&alphabet))
+        }
+        EncodingStd::Base58 => shares_base58_to_bytes(s),
+        EncodingStd::Base32 | EncodingStd::Base32NoPad => shares_base32_to_bytes(s),
+    }
+}
+
+/// Decodes slice of shares strings encoded in given encoding standard to slice of shares in bytes,
+/// rejecting encodings that are not canonical.
+/// Base64 with non-zero trailing bits or improper padding and hex with uppercase letters
+/// are rejected.
+///
+/// # Argument
+///
+/// * `s`  - vector of shares strings to decode.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{decode_shares_strict, encode_shares_bytes, EncodingStd};
+///
+///let secret_shares: Vec<Vec<u8>> = vec![vec![1;128], vec![2;128], vec![3;128], vec![4;128]];
+///
+///let encoding = EncodingStd::Base64;
+///let enc = encode_shares_bytes(secret_shares, encoding.clone());
+///let _dec = decode_shares_strict(&enc, encoding).unwrap();
+///
+///assert!(decode_shares_strict(&["AB==".to_string()], EncodingStd::Base64).is_err());
+///```
+///
+pub fn decode_shares_strict(s: &[String], encoding: EncodingStd) -> Result<Vec<Vec<u8>>, SSSError> {
+    match encoding {
+        EncodingStd::Hex => shares_hex_to_bytes_strict(s, false),
+        EncodingStd::HexUpper => shares_hex_to_bytes_strict(s, true),
+        EncodingStd::Base64 => shares_base64_to_bytes_strict(s),
+        EncodingStd::Base64UrlSafe => shares_base64_to_bytes_strict_with(s, &URL_SAFE_BASE64),
+        EncodingStd::Base64Custom(alphabet) => {
+            shares_base64_to_bytes_strict_with(s, &custom_base64_strict(&alphabet))
+        }
+        EncodingStd::Base58 => shares_base58_to_bytes_strict(s),
+        EncodingStd::Base32 => shares_base32_to_bytes_strict(s, true),
+        EncodingStd::Base32NoPad => shares_base32_to_bytes_strict(s, false),
+    }
+}
+
+/// Transcodes slice of shares strings from one encoding standard to another.
+/// Share bytes are not modified, only the textual representation changes.
+///
+/// # Argument
+///
+/// * `shares`  - slice of shares strings encoded in `from` encoding standard.
+/// * `from`    - encoding standard the shares are currently encoded in.
+/// * `to`      - encoding standard to encode the shares to.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{encode_shares_bytes, transcode_shares, EncodingStd};
+///
+///let secret_shares: Vec<Vec<u8>> = vec![vec![1;128], vec![2;128], vec![3;128], vec![4;128]];
+///
+///let hex = encode_shares_bytes(secret_shares, EncodingStd::Hex);
+///let _base64 = transcode_shares(&hex, EncodingStd::Hex, EncodingStd::Base64).unwrap();
+///```
+///
+pub fn transcode_shares(
+    shares: &[String],
+    from: EncodingStd,
+    to: EncodingStd,
+) -> Result<Vec<String>, SSSError> {
+    let mut result = Vec::with_capacity(shares.len());
+    for (i, share) in shares.iter().enumerate() {
+        let bytes = decode_secret_to_bytes(share, from.clone()).map_err(|e| {
+            SSSError::WithReason(format!("Share at index {i} failed to decode: {e}"))
+        })?;
+        result.push(encode_secret_bytes(&bytes, to.clone()));
+    }
+
+    Ok(result)
+}
+
+/// Combines shares to secret and encodes recovered secret in given encoding standard.
+/// The returned string holds the secret and should be handled accordingly.
+///
+/// # Argument
+///
+/// * `shares`    - vector of shares, at least minimum shares amount.
+/// * `encoding`  - encoding standard to encode the recovered secret in.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_to_encoded, create_std, decode_secret_to_bytes, EncodingStd};
+///
+///let secret = "6e".repeat(64);
+///let secret_bytes = decode_secret_to_bytes(&secret, EncodingStd::Hex).unwrap();
+///let secret_shares: Vec<Vec<u8>> = create_std(2, 3, &secret_bytes).unwrap();
+///let recovered = combine_to_encoded(secret_shares, EncodingStd::Hex).unwrap();
+///assert_eq!(secret, recovered);
+///```
+///
+pub fn combine_to_encoded(shares: Vec<Vec<u8>>, encoding: EncodingStd) -> Result<String, SSSError> {
+    let secret = shamirss::combine_shares(shares)?;
+    Ok(encode_secret_bytes(&secret, encoding))
+}
+
+/// Creates shared secrets from given secret and encodes them in given encoding standard,
+/// works as create_std followed by encode_shares_bytes.
+///
+/// # Argument
+///
+/// * `min_shares_count`     - minimum shares amount needed to recreate the secret.
+/// * `total_shares_count`   - total shares amount to create.
+/// * `secret`               - secret to be shared, divisible by 32 without rest.
+/// * `encoding`             - encoding standard to encode the shares in.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_encoded, EncodingStd};
+///
+///let secret = [110; 64];
+///let secret_shares: Vec<String> = create_encoded(2, 3, &secret, EncodingStd::Hex).unwrap();
+///assert_eq!(secret_shares.len(), 3);
+///```
+///
+pub fn create_encoded(
+    min_shares_count: usize,
+    total_shares_count: usize,
+    secret: &[u8],
+    encoding: EncodingStd,
+) -> Result<Vec<String>, SSSError> {
+    let shares = create_std(min_shares_count, total_shares_count, secret)?;
+    Ok(encode_shares_bytes(shares, encoding))
+}
+
+/// Decodes shares from given encoding standard and combines them to a secret,
+/// works as decode_shares_to_bytes followed by combine_std.
+///
+/// # Argument
+///
+/// * `shares`    - slice of encoded shares, equal or more the minimal share count.
+/// * `encoding`  - encoding standard the shares are encoded in.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_encoded, create_encoded, EncodingStd};
+///
+///let secret = [110; 64];
+///let secret_shares: Vec<String> = create_encoded(2, 3, &secret, EncodingStd::Hex).unwrap();
+///let secret_recreated = combine_encoded(&secret_shares[1..], EncodingStd::Hex).unwrap();
+///assert_eq!(secret.to_vec(), secret_recreated);
+///```
+///
+pub fn combine_encoded(shares: &[String], encoding: EncodingStd) -> Result<Vec<u8>, SSSError> {
+    combine_std(decode_shares_to_bytes(shares, encoding)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_combine_shares_of_configured_block_sizes() -> Result<(), SSSError> {
+        use openssl::{bn::BigNum, rand::rand_bytes};
+
+        for (block, offset) in [(16, 51), (31, 0), (64, 75)] {
+            let prime = if offset == 0 {
+                BigNum::from_dec_str(operations::LEGACY_PRIME)?
+            } else {
+                let mut prime = BigNum::new()?;
+                prime.set_bit(block as i32 * 8)?;
+                prime.add_word(offset)?;
+                prime
+            };
+            let config = SplitConfig::new(block, prime)?;
+            let mut secret = vec![0xff; block * 3];
+            rand_bytes(&mut secret[block..])?;
+
+            let shares = create_with_config(3, 5, &secret, &config)?;
+            assert_eq!(shares[0].len(), 3 * 2 * config.element_size());
+            assert_eq!(combine_with_config(shares[2..].to_vec(), &config)?, secret);
+            assert!(matches!(
+                create_with_config(3, 5, &secret[1..], &config),
+                Err(SSSError::SecretNotAligned { .. })
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_checked_share_with_flipped_bit_by_index() -> Result<(), SSSError> {
+        let secret = vec![6; 96];
+        let mut shares = create_std_checked(3, 5, &secret)?;
+        assert_eq!(shares[0].len(), 1 + 2 * secret.len() + CHECKSUM_SIZE);
+        assert_eq!(combine_std_checked(shares[..3].to_vec())?, secret);
+        let encoded = encode_shares_bytes(shares.clone(), EncodingStd::Base64);
+        let decoded = decode_shares_to_bytes(&encoded, EncodingStd::Base64)?;
+        assert_eq!(combine_std_checked(decoded[2..].to_vec())?, secret);
+
+        shares[3][40] ^= 0x10;
+        match combine_std_checked(shares[1..].to_vec()) {
+            Err(SSSError::WithReason(reason)) => {
+                assert_eq!(reason, "share checksum mismatch at index 2")
+            }
+            other => panic!("expected checksum mismatch, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_return_granular_size_errors() -> Result<(), SSSError> {
+        assert!(matches!(
+            create_std(3, 2, &[1; 64]),
+            Err(SSSError::MinGreaterThanShares { min: 3, shares: 2 })
+        ));
+        assert!(matches!(
+            create_std(2, 3, &[1; 33]),
+            Err(SSSError::SecretNotAligned { len: 33, block: 32 })
+        ));
+
+        let shares = create_std(2, 3, &[1; 64])?;
+        let short_shares = create_std(2, 3, &[1; 32])?;
+        assert!(matches!(
+            combine_std(vec![shares[0][..75].to_vec(), shares[1][..75].to_vec()]),
+            Err(SSSError::ShareNotAligned { len: 74, block: 64 })
+        ));
+        let mismatch = combine_std(vec![shares[0].clone(), short_shares[1].clone()]).unwrap_err();
+        assert!(matches!(
+            mismatch,
+            SSSError::ShareSizeMismatch {
+                expected: 128,
+                got: 64
+            }
+        ));
+        assert_eq!(mismatch.kind(), errors::SSSErrorKind::ShareSizeMismatch);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_indexed_shares_half_the_size_of_std_shares() -> Result<(), SSSError> {
+        let secret = vec![5; 128];
+        let indexed = create_indexed(3, 5, &secret)?;
+        let std = create_std(3, 5, &secret)?;
+        assert_eq!(indexed[0].len(), 1 + secret.len());
+        assert_eq!(std[0].len(), 1 + 2 * secret.len());
+        assert_eq!(combine_indexed(indexed[1..4].to_vec())?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_transcode_shares_from_hex_to_base64_and_back() -> Result<(), SSSError> {
+        let secret = vec![7; 128];
+        let shares = create_std(3, 5, &secret)?;
+        let hex = encode_shares_bytes(shares.clone(), EncodingStd::Hex);
+
+        let base64 = transcode_shares(&hex, EncodingStd::Hex, EncodingStd::Base64)?;
+        assert_eq!(base64, encode_shares_bytes(shares, EncodingStd::Base64));
+
+        let hex_back = transcode_shares(&base64, EncodingStd::Base64, EncodingStd::Hex)?;
+        assert_eq!(hex, hex_back);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn it_should_combine_shares_from_matrix_rows() -> Result<(), SSSError> {
+        use ndarray::{Axis, Slice};
+
+        let secret = vec![3; 96];
+        let shares = create_std(3, 5, &secret)?;
+        let matrix = ndarray::Array2::from_shape_vec((5, shares[0].len()), shares.concat())
+            .map_err(|e| SSSError::WithReason(e.to_string()))?;
+        assert_eq!(combine_shares_ndarray(matrix.view())?, secret);
+        let subset = matrix.select(Axis(0), &[1, 2, 4]);
+        assert_eq!(combine_shares_ndarray(subset.view())?, secret);
+
+        let misaligned = matrix.slice_axis(Axis(1), Slice::from(..shares[0].len() - 2));
+        assert!(combine_shares_ndarray(misaligned).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn it_should_combine_shares_to_zeroizing_secret() -> Result<(), SSSError> {
+        let secret = vec![9; 64];
+        let shares = create_std(2, 3, &secret)?;
+        let recreated: zeroize::Zeroizing<Vec<u8>> = combine_shares_zeroizing(shares)?;
+        assert_eq!(secret, *recreated);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_shares_to_encoded_secret() -> Result<(), SSSError> {
+        let secret = "0123456789abcdef".repeat(8);
+        let secret_bytes = decode_secret_to_bytes(&secret, EncodingStd::Hex)?;
+        let shares = create_std(3, 5, &secret_bytes)?;
+        assert_eq!(
+            combine_to_encoded(shares.clone(), EncodingStd::Hex)?,
+            secret
+        );
+        assert_eq!(
+            combine_to_encoded(shares, EncodingStd::Base64)?,
+            encode_secret_bytes(&secret_bytes, EncodingStd::Base64)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_encoded_shares_and_combine_encoded() -> Result<(), SSSError> {
+        let secret = vec![8; 96];
+        for encoding in [EncodingStd::Hex, EncodingStd::Base64, EncodingStd::Base58] {
+            let shares = create_encoded(3, 5, &secret, encoding.clone())?;
+            let decoded = decode_shares_to_bytes(&shares, encoding.clone())?;
+            assert_eq!(combine_std(decoded)?, secret);
+            assert_eq!(combine_encoded(&shares[2..], encoding.clone())?, secret);
+            assert!(combine_encoded(&["not encoded".to_string()], encoding).is_err());
+        }
+        assert!(create_encoded(3, 5, &[8; 33], EncodingStd::Hex).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_shares_with_threshold_same_as_raw_create() -> Result<(), SSSError> {
+        let secret = vec![4; 96];
+        let typed = create_std_t(Threshold::new(3, 5)?, &secret)?;
+        let raw = create_std(3, 5, &secret)?;
+        assert_eq!(typed.len(), raw.len());
+        assert_eq!(typed[0].len(), raw[0].len());
+        assert_eq!(combine_std(typed[2..].to_vec())?, secret);
+        assert_eq!(combine_std(raw[2..].to_vec())?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_shares_from_framed_reader() -> Result<(), SSSError> {
+        let secret = vec![6; 64];
+        let mut framed = Vec::new();
+        for share in create_std(3, 5, &secret)?.into_iter().take(3) {
+            framed.extend((share.len() as u32).to_be_bytes());
+            framed.extend(share);
+        }
+        let reader = std::io::Cursor::new(&framed);
+        assert_eq!(combine_shares_from_reader(reader)?, secret);
+
+        let truncated = std::io::Cursor::new(&framed[..framed.len() - 3]);
+        assert!(combine_shares_from_reader(truncated).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_decode_labeled_shares_to_share_bytes() -> Result<(), SSSError> {
+        let secret = vec![8; 64];
+        let shares = create_std(3, 5, &secret)?;
+        for encoding in [EncodingStd::Hex, EncodingStd::Base64] {
+            let labeled = label_shares(&shares, 3, 5, encoding.clone());
+            assert_eq!(labeled[0].lines().next(), Some("# shamir v2 3/5 idx=1"));
+            assert_eq!(labeled[4].lines().next(), Some("# shamir v2 3/5 idx=5"));
+            let decoded = decode_shares_to_bytes(&labeled, encoding)?;
+            assert_eq!(decoded, shares);
+            assert_eq!(combine_std(decoded[1..4].to_vec())?, secret);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_cross_check_labels_when_combining_labeled_shares() -> Result<(), SSSError> {
+        let secret = vec![8; 64];
+        let shares = create_std(2, 3, &secret)?;
+        let labeled = label_shares(&shares, 2, 3, EncodingStd::Hex);
+        assert_eq!(combine_labeled_shares(&labeled, EncodingStd::Hex)?, secret);
+
+        let mut version_mismatch = labeled.clone();
+        version_mismatch[0] = version_mismatch[0].replacen("v2", "v1", 1);
+        assert!(combine_labeled_shares(&version_mismatch, EncodingStd::Hex).is_err());
+
+        let mut threshold_mismatch = labeled.clone();
+        threshold_mismatch[1] = threshold_mismatch[1].replacen("2/3", "3/3", 1);
+        assert!(combine_labeled_shares(&threshold_mismatch, EncodingStd::Hex).is_err());
+
+        let mut index_repeated = labeled.clone();
+        index_repeated[1] = index_repeated[1].replacen("idx=2", "idx=1", 1);
+        assert!(combine_labeled_shares(&index_repeated, EncodingStd::Hex).is_err());
+
+        let legacy: Vec<Vec<u8>> = shamirss::create_shares_with_legacy_prime(2, 3, &secret)?
+            .iter()
+            .map(|s| s[1..].to_vec())
+            .collect();
+        let legacy_labeled = label_shares(&legacy, 2, 3, EncodingStd::Hex);
+        assert!(legacy_labeled[0].starts_with("# shamir v0 2/3 idx=1\n"));
+        assert_eq!(
+            combine_labeled_shares(&legacy_labeled, EncodingStd::Hex)?,
+            secret
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_label_and_combine_shares_with_threshold_header() -> Result<(), SSSError> {
+        let secret = vec![8; 64];
+        let shares = create_shares_with_header(3, 5, &secret)?;
+        let labeled = label_shares(&shares, 3, 5, EncodingStd::Base64);
+        assert!(labeled[1].starts_with("# shamir v3 3/5 idx=2\n"));
+        assert_eq!(
+            combine_labeled_shares(&labeled[1..4], EncodingStd::Base64)?,
+            secret
+        );
+
+        let mut version_mismatch = labeled.clone();
+        version_mismatch[2] = version_mismatch[2].replacen("v3", "v2", 1);
+        assert!(combine_labeled_shares(&version_mismatch, EncodingStd::Base64).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_sanitize_and_combine_messy_pasted_shares() -> Result<(), SSSError> {
+        let secret = vec![5; 96];
+        let shares = create_std(3, 5, &secret)?;
+        let encoded = encode_shares_bytes(shares[..3].to_vec(), EncodingStd::Hex);
+        let lines = vec![
+            String::new(),
+            format!("  {}", encoded[0]),
+            "# pasted from email".to_string(),
+            encoded[1].clone(),
+            "   ".to_string(),
+            format!("{}\t", encoded[0]),
+            encoded[2].clone(),
+            encoded[1].clone(),
+        ];
+
+        let sanitized = sanitize_shares(&lines, EncodingStd::Hex)?;
+        assert_eq!(sanitized.shares, shares[..3].to_vec());
+        assert_eq!(sanitized.blanks, 2);
+        assert_eq!(sanitized.comments, 1);
+        assert_eq!(sanitized.duplicates, 2);
+        assert_eq!(sanitize_and_combine(&lines, EncodingStd::Hex)?, secret);
+
+        let duplicated = vec![encoded[0].clone(), encoded[0].clone()];
+        assert!(sanitize_and_combine(&duplicated, EncodingStd::Hex).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_report_reconstruction_details() -> Result<(), SSSError> {
+        let secret = vec![2; 64];
+        let shares = shamirss::create_shares_with_legacy_prime(3, 5, &secret)?;
+        let mut mixed = shares[..4].to_vec();
+        mixed[0] = mixed[0][1..].to_vec();
+
+        let reconstruction = combine_detailed(mixed)?;
+        assert_eq!(reconstruction.secret, secret);
+        assert_eq!(reconstruction.layout, ShareLayout::Versioned);
+        assert_eq!(reconstruction.shares_used, 4);
+        assert_eq!(reconstruction.threshold, None);
+        assert_eq!(reconstruction.versioned_shares, 3);
+        assert_eq!(reconstruction.legacy_shares, 1);
+        assert!(!reconstruction.integrity_verified);
+
+        let legacy = shares[..3]
+            .iter()
+            .map(|share| share[1..].to_vec())
+            .collect();
+        assert_eq!(combine_detailed(legacy)?.layout, ShareLayout::Legacy);
+        let with_header = create_shares_with_header(3, 5, &secret)?;
+        assert_eq!(
+            combine_detailed(with_header[..3].to_vec())?.layout,
+            ShareLayout::ThresholdHeader
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_report_reconstruction_details_of_no_shares() {
+        for shares in [vec![], vec![vec![]]] {
+            match combine_detailed(shares) {
+                Err(SSSError::WithReason(reason)) => assert_eq!(reason, "no shares provided"),
+                other => panic!("expected no shares provided error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_report_threshold_and_integrity_of_checksummed_header_shares(
+    ) -> Result<(), SSSError> {
+        let secret = vec![3; 96];
+        let mut shares = create_shares_with_header(3, 5, &secret)?;
+        for share in shares.iter_mut() {
+            let checksum = crc32(share);
+            share.extend(checksum.to_be_bytes());
+        }
+        let mut duplicated = shares[1..4].to_vec();
+        duplicated.push(shares[2].clone());
+
+        let reconstruction = combine_detailed(duplicated)?;
+        assert_eq!(reconstruction.secret, secret);
+        assert_eq!(reconstruction.layout, ShareLayout::Checksummed);
+        assert_eq!(reconstruction.shares_used, 3);
+        assert_eq!(reconstruction.threshold, Some(3));
+        assert_eq!(reconstruction.versioned_shares, 4);
+        assert_eq!(reconstruction.legacy_shares, 0);
+        assert!(reconstruction.integrity_verified);
+
+        let mut insufficient = shares[..2].to_vec();
+        insufficient.push(shares[1].clone());
+        assert!(combine_detailed(insufficient).is_err());
+        shares[4][10] ^= 1;
+        match combine_detailed(shares[2..].to_vec()) {
+            Err(SSSError::WithReason(reason)) => {
+                assert_eq!(reason, "share checksum mismatch at index 2")
+            }
+            other => panic!("expected checksum mismatch, got {other:?}"),
+        }
+
+        match combine_detailed(vec![vec![9; SHARE_UNIT_BYTES + 1], shares[0].clone()]) {
+            Err(SSSError::WithReason(reason)) => {
+                assert_eq!(reason, "unsupported share format version 9")
+            }
+            other => panic!("expected unsupported version, got {other:?}"),
+        }
+
+        let checked = create_std_checked(2, 3, &secret)?;
+        let reconstruction = combine_detailed(checked[1..].to_vec())?;
+        assert_eq!(reconstruction.secret, secret);
+        assert_eq!(reconstruction.shares_used, 2);
+        assert_eq!(reconstruction.threshold, None);
+        assert_eq!(reconstruction.versioned_shares, 2);
+        assert_eq!(reconstruction.layout, ShareLayout::Checksummed);
+        assert!(reconstruction.integrity_verified);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_shares_from_lines() -> Result<(), SSSError> {
+        let secret = vec![1; 128];
+        let shares = create_std(3, 5, &secret)?;
+        let lines = encode_shares_bytes(shares[1..4].to_vec(), EncodingStd::Base64);
+        let input = format!("# recovery\n{}\n\n{}\n{}\n", lines[0], lines[1], lines[2]);
+        let reader = std::io::Cursor::new(input);
+        assert_eq!(combine_from_lines(reader, EncodingStd::Base64)?, secret);
+
+        let invalid_utf8 = std::io::Cursor::new(vec![0xff, 0xfe, b'\n']);
+        match combine_from_lines(invalid_utf8, EncodingStd::Base64) {
+            Err(e) => assert_eq!(e.kind(), errors::SSSErrorKind::Io),
+            Ok(_) => panic!("expected reading invalid utf-8 to fail"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_detect_plausible_shares() -> Result<(), SSSError> {
+        let shares = create_std(2, 3, &[3; 96])?;
+        assert!(looks_like_share(&shares[0]));
+        assert!(looks_like_share(&shares[0][SHARE_HEADER_BYTES..]));
+
+        assert!(!looks_like_share(&[]));
+        assert!(!looks_like_share(&shares[0][..SHARE_HEADER_BYTES]));
+        assert!(!looks_like_share(&shares[0][..shares[0].len() - 2]));
+        let mut unsupported = shares[0].clone();
+        unsupported[0] = 0xfe;
+        assert!(!looks_like_share(&unsupported));
+        let mut misaligned = vec![0; SHARE_UNIT_BYTES + 7];
+        openssl::rand::rand_bytes(&mut misaligned)?;
+        assert!(!looks_like_share(&misaligned));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_shares_and_shred_secret() -> Result<(), SSSError> {
+        let mut secret = vec![0; 96];
+        openssl::rand::rand_bytes(&mut secret)?;
+        let expected = secret.clone();
+        let shares = create_shares_shred(3, 5, &mut secret)?;
+        assert!(secret.iter().all(|b| *b == 0));
+        assert_eq!(combine_std(shares[..3].to_vec())?, expected);
+
+        let mut invalid = vec![9; 33];
+        assert!(create_shares_shred(3, 5, &mut invalid).is_err());
+        assert!(invalid.iter().all(|b| *b == 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_encode_and_decode_shares_with_custom_base64_alphabet() -> Result<(), SSSError> {
+        let alphabet = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz-_";
+        let encoding = EncodingStd::base64_custom(alphabet)?;
+        let shares = create_std(2, 3, &[4; 64])?;
+
+        let encoded = encode_shares_bytes(shares.clone(), encoding.clone());
+        assert_ne!(
+            encoded,
+            encode_shares_bytes(shares.clone(), EncodingStd::Base64)
+        );
+        assert_eq!(decode_shares_to_bytes(&encoded, encoding.clone())?, shares);
+        assert_eq!(decode_shares_strict(&encoded, encoding)?, shares);
+        assert!(!matches!(
+            decode_shares_to_bytes(&encoded, EncodingStd::Base64),
+            Ok(decoded) if decoded == shares
+        ));
+
+        assert!(EncodingStd::base64_custom(&alphabet.replace('_', "-")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_labeled_shares_of_unexpected_threshold() -> Result<(), SSSError> {
+        let secret = vec![8; 64];
+        let shares = create_std(3, 5, &secret)?;
+        let mut labeled = label_shares(&shares, 3, 5, EncodingStd::Hex);
+        let expected = Threshold::new(3, 5)?;
+        assert_eq!(
+            combine_labeled_shares_expect_threshold(&labeled, EncodingStd::Hex, expected)?,
+            secret
+        );
+
+        labeled[4] =
+            label_shares(&shares[4..], 4, 5, EncodingStd::Hex)[0].replacen("idx=1", "idx=5", 1);
+        match combine_labeled_shares_expect_threshold(&labeled, EncodingStd::Hex, expected) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("expected 3/5")),
+            _ => panic!("expected share labeled 4/5 to be rejected"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_report_index_of_share_failing_to_transcode() {
+        let shares = vec!["00ff".to_string(), "not hex".to_string()];
+        match transcode_shares(&shares, EncodingStd::Hex, EncodingStd::Base64) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("index 1")),
+            _ => panic!("expected transcode to fail at index 1"),
+        }
+    }
+
+    #[test]
+    fn it_should_split_and_combine_bip39_entropy() -> Result<(), SSSError> {
+        let mut entropy = [0u8; BIP39_ENTROPY_BYTES];
+        entropy
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, b)| *b = i as u8);
+        let shares = split_bip39_entropy(&entropy, 3, 5)?;
+        assert_eq!(shares.len(), 5);
+        assert_eq!(combine_bip39_entropy(shares[2..].to_vec())?, entropy);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_combining_bip39_entropy_of_wrong_length() -> Result<(), SSSError> {
+        let shares = create_std(2, 3, &[9; 64])?;
+        match combine_bip39_entropy(shares[..2].to_vec()) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("64 bytes")),
+            _ => panic!("expected 64 bytes secret to be rejected as BIP39 entropy"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_repair_corrupted_share() -> Result<(), SSSError> {
+        let secret = vec![11; 96];
+        let original = create_std(3, 5, &secret)?;
+        let mut shares = original.clone();
+        shares[3][SHARE_HEADER_BYTES + 40] ^= 0xff;
+        assert_ne!(combine_std(shares[2..5].to_vec())?, secret);
+
+        shares[3] = repair_share(shares[..3].to_vec(), &shares[3], 3)?;
+        assert_eq!(shares[3], original[3]);
+        assert_eq!(combine_std(shares[2..5].to_vec())?, secret);
+
+        assert!(repair_share(shares[..2].to_vec(), &shares[3], 3).is_err());
+        assert!(repair_share(shares[..3].to_vec(), &shares[3][..SHARE_UNIT_BYTES], 3).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_decode_uppercase_and_grouped_hex() -> Result<(), SSSError> {
+        let secret = vec![0xab, 0xcd, 0xef, 0x01, 0x23];
+        let upper = encode_secret_bytes(&secret, EncodingStd::HexUpper);
+        assert_eq!(upper, "ABCDEF0123");
+        assert_eq!(decode_secret_to_bytes(&upper, EncodingStd::Hex)?, secret);
+        assert_eq!(
+            decode_secret_to_bytes(&upper, EncodingStd::HexUpper)?,
+            secret
+        );
+
+        let grouped = hex_grouped(&secret, 4, " ");
+        assert_eq!(grouped, "abcd ef01 23");
+        assert_eq!(decode_secret_to_bytes(&grouped, EncodingStd::Hex)?, secret);
+        assert_eq!(
+            decode_secret_to_bytes("AbCd-eF01\n23", EncodingStd::Hex)?,
+            secret
+        );
+        assert_eq!(hex_grouped(&secret, 0, " "), "abcdef0123");
+
+        let shares = create_std(2, 3, &[5; 32])?;
+        let grouped_shares: Vec<String> = shares
+            .iter()
+            .map(|share| hex_grouped(share, 8, " "))
+            .collect();
+        assert_eq!(
+            decode_shares_to_bytes(&grouped_shares, EncodingStd::HexUpper)?,
+            shares
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_standard_base64_as_url_safe() -> Result<(), SSSError> {
+        let secret = vec![0xfb, 0xff, 0xbf, 0x01];
+        let standard = encode_secret_bytes(&secret, EncodingStd::Base64);
+        assert!(standard.contains('/') && standard.contains('='));
+        assert!(decode_secret_to_bytes(&standard, EncodingStd::Base64UrlSafe).is_err());
+
+        let url_safe = encode_secret_bytes(&secret, EncodingStd::Base64UrlSafe);
+        assert_eq!(url_safe, "-_-_AQ");
+        assert_eq!(
+            decode_secret_to_bytes(&url_safe, EncodingStd::Base64UrlSafe)?,
+            secret
+        );
+        let shares = vec![standard];
+        assert!(decode_shares_to_bytes(&shares, EncodingStd::Base64UrlSafe).is_err());
+        assert!(decode_shares_strict(&shares, EncodingStd::Base64UrlSafe).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_round_trip_secret_and_shares_in_every_encoding() -> Result<(), SSSError> {
+        let encodings = [
+            EncodingStd::Hex,
+            EncodingStd::HexUpper,
+            EncodingStd::Base64,
+            EncodingStd::Base64UrlSafe,
+            EncodingStd::base64_custom(
+                "ZYXWVUTSRQPONMLKJIHGFEDCBAzyxwvutsrqponmlkjihgfedcba9876543210-_",
+            )?,
+            EncodingStd::Base58,
+            EncodingStd::Base32,
+            EncodingStd::Base32NoPad,
+        ];
+        let mut secret = vec![0; 96];
+        secret
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, b)| *b = i as u8);
+
+        for encoding in encodings {
+            // New encodings shall be added to the list above.
+            match encoding {
+                EncodingStd::Hex
+                | EncodingStd::HexUpper
+                | EncodingStd::Base64
+                | EncodingStd::Base64UrlSafe
+                | EncodingStd::Base64Custom(_)
+                | EncodingStd::Base58
+                | EncodingStd::Base32
+                | EncodingStd::Base32NoPad => (),
+            }
+
+            let encoded_secret = encode_secret_bytes(&secret, encoding.clone());
+            assert_eq!(
+                decode_secret_to_bytes(&encoded_secret, encoding.clone())?,
+                secret
+            );
+
+            let shares = create_std(3, 5, &secret)?;
+            let encoded_shares = encode_shares_bytes(shares.clone(), encoding.clone());
+            let decoded_shares = decode_shares_to_bytes(&encoded_shares, encoding)?;
+            assert_eq!(decoded_shares, shares);
+            assert_eq!(combine_std(decoded_shares[1..4].to_vec())?, secret);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_shares_paired_with_labels() -> Result<(), SSSError> {
+        let secret = vec![5; 64];
+        let labels: Vec<String> = ["alice", "bob", "carol", "dave"]
+            .iter()
+            .map(|label| label.to_string())
+            .collect();
+        let labeled = create_shares_labeled(3, 4, &secret, &labels)?;
+        assert_eq!(
+            labeled.iter().map(|(label, _)| label).collect::<Vec<_>>(),
+            labels.iter().collect::<Vec<_>>()
+        );
+        let shares: Vec<Vec<u8>> = labeled.into_iter().map(|(_, share)| share).collect();
+        assert_eq!(combine_std(shares[1..].to_vec())?, secret);
+
+        match create_shares_labeled(3, 5, &secret, &labels) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("Labels count 4")),
+            _ => panic!("expected labels count mismatch to be rejected"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_padded_and_combine_padded_for_any_secret_size() -> Result<(), SSSError> {
+        for size in [0, 1, 31, 33, 1000] {
+            let secret: Vec<u8> = (0..size).map(|i| (i % 251) as u8 + 1).collect();
+            let shares = create_padded(3, 5, &secret)?;
+            let expected_chunks = (PADDED_LENGTH_BYTES + size).div_ceil(CHUNK_BYTES);
+            assert_eq!(
+                shares[0].len(),
+                SHARE_HEADER_BYTES + expected_chunks * SHARE_UNIT_BYTES
+            );
+            assert_eq!(combine_padded(shares[1..4].to_vec())?, secret);
+        }
+
+        let unpadded = create_std(2, 3, &[0xff; 64])?;
+        assert!(combine_padded(unpadded[..2].to_vec()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_and_combine_with_custom_prime() -> Result<(), SSSError> {
+        let prime = openssl::bn::BigNum::from_dec_str(
+            "115792089237316195423570985008687907853269984665640564039457584007913129640237",
+        )?;
+        let secret = vec![0xff; 96];
+        let shares = create_with_prime(3, 5, &secret, &prime)?;
+        assert_eq!(combine_with_prime(shares[2..].to_vec(), &prime)?, secret);
+
+        assert_ne!(combine_std(shares[2..].to_vec())?, secret);
+        let default_shares = create_std(3, 5, &secret)?;
+        assert_ne!(
+            combine_with_prime(default_shares[2..].to_vec(), &prime)?,
+            secret
+        );
+
+        let composite = openssl::bn::BigNum::from_dec_str(
+            "115792089237316195423570985008687907853269984665640564039457584007913129640235",
+        )?;
+        let small = openssl::bn::BigNum::from_dec_str(operations::LEGACY_PRIME)?;
+        for invalid in [&composite, &small] {
+            assert!(create_with_prime(3, 5, &secret, invalid).is_err());
+            assert!(combine_with_prime(shares[2..].to_vec(), invalid).is_err());
+        }
+
+        Ok(())
+    }
+
+    struct SeededRng(rand::rngs::StdRng);
+
+    impl ShareRng for SeededRng {
+        fn gen_below(
+            &mut self,
+            upper: &openssl::bn::BigNum,
+        ) -> Result<openssl::bn::BigNum, SSSError> {
+            let mut bytes = vec![0; upper.num_bytes() as usize + 8];
+            rand::RngCore::fill_bytes(&mut self.0, &mut bytes);
+            let mut ctx = openssl::bn::BigNumContext::new()?;
+            let mut result = openssl::bn::BigNum::new()?;
+            let wide = openssl::bn::BigNum::from_slice(&bytes)?;
+            result.nnmod(&wide, upper, &mut ctx)?;
+            Ok(result)
+        }
+    }
+
+    #[test]
+    fn it_should_create_identical_shares_with_seeded_rng() -> Result<(), SSSError> {
+        use rand::SeedableRng;
+
+        let secret = vec![3; 96];
+        let seeded = |seed| SeededRng(rand::rngs::StdRng::seed_from_u64(seed));
+        let shares = create_std_with_rng(3, 5, &secret, &mut seeded(42))?;
+        assert_eq!(create_std_with_rng(3, 5, &secret, &mut seeded(42))?, shares);
+        assert_ne!(create_std_with_rng(3, 5, &secret, &mut seeded(43))?, shares);
+        assert_eq!(combine_std(shares[2..].to_vec())?, secret);
+
+        Ok(())
     }
 }
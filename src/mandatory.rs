@@ -0,0 +1,150 @@
+use crate::{
+    errors::SSSError,
+    operations::is_proper_size,
+    shamirss::{combine_shares, create_shares},
+};
+use openssl::rand::rand_bytes;
+
+/// MandatoryShares holds shares of the secret where all mandatory shares are required
+/// together with the minimal amount of regular shares.
+///
+#[derive(Debug, Clone)]
+pub struct MandatoryShares {
+    pub mandatory: Vec<Vec<u8>>,
+    pub regular: Vec<Vec<u8>>,
+}
+
+/// Creates shares of the secret, where every mandatory share is required to recreate it.
+/// Secret is split into XOR of two parts. First part is split with XOR into mandatory_count
+/// mandatory shares, so all of them are needed. Second part is Shamir shared into
+/// total - mandatory_count regular shares, so min of them are needed.
+/// Any set of shares missing a mandatory share or holding less then min regular shares
+/// gives no information about the secret.
+///
+/// # Argument
+///
+/// * `min`              - minimal amount of regular shares required to recreate the secret.
+/// * `total`            - total amount of shares, mandatory shares included.
+/// * `secret`           - secret to be shared, divisible by 32 without rest.
+/// * `mandatory_count`  - amount of mandatory shares.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_shares_with_mandatory, create_shares_with_mandatory};
+///
+///let secret = vec![7; 64];
+///let shares = create_shares_with_mandatory(2, 5, &secret, 1).unwrap();
+///assert_eq!(shares.mandatory.len(), 1);
+///assert_eq!(shares.regular.len(), 4);
+///let secret_recreated =
+///    combine_shares_with_mandatory(&shares.mandatory, shares.regular[1..3].to_vec()).unwrap();
+///assert_eq!(secret, secret_recreated);
+///```
+///
+pub fn create_shares_with_mandatory(
+    min: usize,
+    total: usize,
+    secret: &[u8],
+    mandatory_count: usize,
+) -> Result<MandatoryShares, SSSError> {
+    if !is_proper_size(secret) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: crate::CHUNK_BYTES,
+        });
+    }
+    if mandatory_count == 0 || mandatory_count >= total {
+        return Err(SSSError::WithReason(format!(
+            "Mandatory shares count has to be between 1 and {}, got {mandatory_count}.",
+            total.saturating_sub(1)
+        )));
+    }
+
+    let mut remainder = secret.to_vec();
+    let mut mandatory = Vec::with_capacity(mandatory_count);
+    for _ in 0..mandatory_count {
+        let mut pad = vec![0; secret.len()];
+        rand_bytes(&mut pad)?;
+        xor_in_place(&mut remainder, &pad);
+        mandatory.push(pad);
+    }
+    let regular = create_shares(min, total - mandatory_count, &remainder)?;
+
+    Ok(MandatoryShares { mandatory, regular })
+}
+
+/// Combines secret from all mandatory shares and at least minimal amount of regular shares.
+///
+/// # Argument
+///
+/// * `mandatory`  - slice of all mandatory shares.
+/// * `regular`    - vector of regular shares, equal or more the minimal share count.
+///
+pub fn combine_shares_with_mandatory(
+    mandatory: &[Vec<u8>],
+    regular: Vec<Vec<u8>>,
+) -> Result<Vec<u8>, SSSError> {
+    if mandatory.is_empty() {
+        return Err(SSSError::WithReason(
+            "All mandatory shares are required to combine.".to_owned(),
+        ));
+    }
+    let mut secret = combine_shares(regular)?;
+    for pad in mandatory.iter() {
+        if pad.len() != secret.len() {
+            return Err(SSSError::WithReason(format!(
+                "Mandatory share shall be {} bytes long, got {}.",
+                secret.len(),
+                pad.len()
+            )));
+        }
+        xor_in_place(&mut secret, pad);
+    }
+
+    Ok(secret)
+}
+
+#[inline(always)]
+fn xor_in_place(target: &mut [u8], pad: &[u8]) {
+    target.iter_mut().zip(pad.iter()).for_each(|(t, p)| *t ^= p);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_create_and_combine_shares_with_mandatory() -> Result<(), SSSError> {
+        let mut secret = vec![0; 96];
+        rand_bytes(&mut secret)?;
+
+        let shares = create_shares_with_mandatory(3, 7, &secret, 2)?;
+        assert_eq!(shares.mandatory.len(), 2);
+        assert_eq!(shares.regular.len(), 5);
+
+        let recreated =
+            combine_shares_with_mandatory(&shares.mandatory, shares.regular[2..].to_vec())?;
+        assert_eq!(recreated, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_combine_without_mandatory_share() -> Result<(), SSSError> {
+        let mut secret = vec![0; 64];
+        rand_bytes(&mut secret)?;
+
+        let shares = create_shares_with_mandatory(2, 5, &secret, 2)?;
+        let missing_one =
+            combine_shares_with_mandatory(&shares.mandatory[..1], shares.regular.clone())?;
+        assert_ne!(missing_one, secret);
+        assert!(combine_shares_with_mandatory(&[], shares.regular.clone()).is_err());
+        assert_ne!(combine_shares(shares.regular)?, secret);
+
+        assert!(create_shares_with_mandatory(2, 5, &secret, 0).is_err());
+        assert!(create_shares_with_mandatory(2, 5, &secret, 4).is_err());
+
+        Ok(())
+    }
+}
@@ -1,12 +1,76 @@
-use crate::errors::SSSError;
-use base64::{engine::general_purpose::STANDARD, Engine as _};
-use openssl::bn::{BigNum, BigNumContextRef};
+use crate::{
+    errors::{Base32Error, Base58Error, SSSError},
+    rng::ShareRng,
+};
+use base64::{
+    alphabet,
+    engine::{
+        general_purpose::{GeneralPurpose, GeneralPurposeConfig, NO_PAD, PAD, STANDARD},
+        DecodePaddingMode,
+    },
+    Engine as _,
+};
+use openssl::bn::{BigNum, BigNumContext, BigNumContextRef};
+use std::borrow::Cow;
+use std::io::Read;
+use std::ops::{Deref, DerefMut};
+use std::sync::OnceLock;
 
-/// Default prime used for mod calculations.
+/// Default prime used for mod calculations, the smallest prime above 2^256 (2^256 + 297).
+/// Every 32 bytes secret chunk is smaller than the prime, so all chunk values survive.
 ///
 pub(crate) const DEFAULT_PRIME: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129640233";
+
+/// Prime used for mod calculations by legacy and version 1 shares (2^256 - 189).
+/// Secret chunks equal or above the prime cannot be recreated from these shares.
+///
+pub(crate) const LEGACY_PRIME: &str =
     "115792089237316195423570985008687907853269984665640564039457584007913129639747";
 
+/// Returns DEFAULT_PRIME parsed once and cached for the process lifetime.
+///
+#[inline(always)]
+pub(crate) fn default_prime() -> Result<&'static BigNum, SSSError> {
+    static PRIME: OnceLock<BigNum> = OnceLock::new();
+    if let Some(prime) = PRIME.get() {
+        return Ok(prime);
+    }
+    let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+    Ok(PRIME.get_or_init(|| prime))
+}
+
+/// Returns LEGACY_PRIME parsed once and cached for the process lifetime.
+///
+#[inline(always)]
+pub(crate) fn legacy_prime() -> Result<&'static BigNum, SSSError> {
+    static PRIME: OnceLock<BigNum> = OnceLock::new();
+    if let Some(prime) = PRIME.get() {
+        return Ok(prime);
+    }
+    let prime = BigNum::from_dec_str(LEGACY_PRIME)?;
+    Ok(PRIME.get_or_init(|| prime))
+}
+
+/// Replaces given context with a fresh one when the `high-security` feature is enabled,
+/// so Openssl clears the scratch of the dropped context. Without the feature does nothing.
+///
+#[cfg(feature = "high-security")]
+#[inline(always)]
+pub(crate) fn refresh_context(ctx: &mut BigNumContext) -> Result<(), SSSError> {
+    *ctx = BigNumContext::new()?;
+    Ok(())
+}
+
+/// Replaces given context with a fresh one when the `high-security` feature is enabled,
+/// so Openssl clears the scratch of the dropped context. Without the feature does nothing.
+///
+#[cfg(not(feature = "high-security"))]
+#[inline(always)]
+pub(crate) fn refresh_context(_ctx: &mut BigNumContext) -> Result<(), SSSError> {
+    Ok(())
+}
+
 /// Maximum initial size of big int is set to 32 bytes to protect against value overflow.
 ///
 pub(crate) const U8S_TO_BIG_INT_INITIAL: usize = 32;
@@ -27,6 +91,22 @@ pub(crate) fn random(upper_limit: &BigNum) -> Result<BigNum, SSSError> {
     Ok(result)
 }
 
+/// Returns random number between 1 and upper_limit - 1.
+/// Zero is rejected and drawn again, as polynomial evaluated at zero is the secret itself.
+///
+#[inline(always)]
+pub(crate) fn random_nonzero<R: ShareRng>(
+    rng: &mut R,
+    upper_limit: &BigNum,
+) -> Result<BigNum, SSSError> {
+    loop {
+        let result = rng.gen_below(upper_limit)?;
+        if result.num_bits() != 0 {
+            return Ok(result);
+        }
+    }
+}
+
 /// Maps bytes to big nums.
 ///
 #[inline(always)]
@@ -47,60 +127,250 @@ pub(crate) fn bytes_to_big_nums(bytes: &[u8]) -> Result<Vec<BigNum>, SSSError> {
     Ok(slice)
 }
 
-/// Maps big nums to bytes.
+/// Maps big nums to bytes, asserting every big num fits U8S_TO_BIG_INT_INITIAL bytes.
 ///
 #[inline(always)]
 pub(crate) fn big_nums_to_bytes(slice: &[BigNum]) -> Vec<u8> {
+    debug_assert!(
+        slice
+            .iter()
+            .all(|big_int| big_int.num_bytes() as usize <= U8S_TO_BIG_INT_INITIAL),
+        "big num does not fit the share field element"
+    );
     let mut result = Vec::with_capacity(slice.len() * U8S_TO_BIG_INT_INITIAL);
     for big_int in slice.iter() {
-        let bytes = big_int.to_vec();
+        let mut bytes = big_int.to_vec();
         if bytes.len() < U8S_TO_BIG_INT_INITIAL {
             result.extend(vec![0; U8S_TO_BIG_INT_INITIAL - bytes.len()].iter());
-            result.extend(bytes);
-        } else {
-            result.extend(bytes);
         }
+        result.extend(bytes.iter());
+        wipe_bytes(&mut bytes);
     }
     result
 }
 
-/// Evaluates polynomial slice.
+/// Overwrites bytes with zeros. With the `zeroize` feature the write cannot be optimized away,
+/// without it zeroing is best effort.
 ///
 #[inline(always)]
-pub(crate) fn evaluate(
+pub(crate) fn wipe_bytes(bytes: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(bytes);
+    #[cfg(not(feature = "zeroize"))]
+    bytes.fill(0);
+}
+
+/// ClearBigNums is implemented by the containers of BigNums derived from the secret.
+///
+pub(crate) trait ClearBigNums {
+    /// Clears all BigNums setting them to zero.
+    ///
+    fn clear_big_nums(&mut self);
+}
+
+impl ClearBigNums for BigNum {
+    fn clear_big_nums(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: ClearBigNums> ClearBigNums for Vec<T> {
+    fn clear_big_nums(&mut self) {
+        self.iter_mut().for_each(ClearBigNums::clear_big_nums);
+    }
+}
+
+impl<A: ClearBigNums, B: ClearBigNums> ClearBigNums for (A, B) {
+    fn clear_big_nums(&mut self) {
+        self.0.clear_big_nums();
+        self.1.clear_big_nums();
+    }
+}
+
+/// ClearOnDrop holds BigNums derived from the secret and clears them when dropped, so they are
+/// cleared on success and error paths alike. BigNum drop frees the memory without clearing it.
+///
+pub(crate) struct ClearOnDrop<T: ClearBigNums>(pub(crate) T);
+
+impl<T: ClearBigNums> Deref for ClearOnDrop<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ClearBigNums> DerefMut for ClearOnDrop<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: ClearBigNums> Drop for ClearOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.clear_big_nums();
+    }
+}
+
+/// ScratchArena holds reusable BigNum temporaries and clears them when reset or dropped.
+/// BigNum drop frees the memory without clearing it, temporaries derived from the secret are
+/// kept in the arena, so they are wiped once the calculation is done.
+///
+pub(crate) struct ScratchArena {
+    nums: Vec<BigNum>,
+}
+
+impl ScratchArena {
+    /// Creates arena holding given amount of temporaries.
+    ///
+    #[inline(always)]
+    pub(crate) fn new(size: usize) -> Result<Self, SSSError> {
+        let nums = (0..size)
+            .map(|_| BigNum::new())
+            .collect::<Result<Vec<BigNum>, _>>()?;
+        Ok(Self { nums })
+    }
+
+    /// Returns first N temporaries, the arena shall hold at least N of them.
+    ///
+    #[inline(always)]
+    pub(crate) fn take<const N: usize>(&mut self) -> &mut [BigNum; N] {
+        (&mut self.nums[..N])
+            .try_into()
+            .expect("scratch arena holds too few temporaries")
+    }
+
+    /// Clears all temporaries setting them to zero.
+    ///
+    #[inline(always)]
+    pub(crate) fn reset(&mut self) {
+        self.nums.iter_mut().for_each(|num| num.clear());
+    }
+}
+
+impl Drop for ScratchArena {
+    fn drop(&mut self) {
+        self.reset();
+    }
+}
+
+/// Evaluates polynomial slice keeping temporaries in given scratch arena.
+///
+#[inline(always)]
+pub(crate) fn evaluate_with(
     ctx: &mut BigNumContextRef,
+    scratch: &mut ScratchArena,
     slice: &[BigNum],
     value: &BigNum,
     prime: &BigNum,
 ) -> Result<BigNum, SSSError> {
+    let [temp] = scratch.take::<1>();
     let mut result = BigNum::new()?;
 
     for i in (0..slice.len()).rev() {
-        let mut temp = BigNum::new()?;
         temp.checked_mul(result.as_ref(), value, ctx)?;
         result.checked_add(temp.as_ref(), &slice[i])?;
         temp.nnmod(result.as_ref(), prime, ctx)?;
-        result = temp
+        std::mem::swap(&mut result, temp);
     }
 
     Ok(result)
 }
 
-/// Decodes hex to bytes.
+/// Decodes hex to bytes, whitespace and '-' or ':' separators are skipped and case is ignored.
 ///
 #[inline(always)]
 pub(crate) fn secret_hex_to_bytes(s: &str) -> Result<Vec<u8>, SSSError> {
-    Ok(hex::decode(s)?)
+    Ok(hex::decode(strip_hex_separators(s).as_ref())?)
+}
+
+/// Returns hex without whitespace and '-' or ':' separators of grouped hex.
+///
+#[inline(always)]
+pub(crate) fn strip_hex_separators(s: &str) -> Cow<'_, str> {
+    let is_separator = |c: char| c.is_whitespace() || c == '-' || c == ':';
+    if !s.contains(is_separator) {
+        return Cow::Borrowed(s);
+    }
+    Cow::Owned(s.chars().filter(|c| !is_separator(*c)).collect())
+}
+
+/// Returns encoded share without label comment lines, lines starting with '#' are skipped.
+///
+#[inline(always)]
+pub(crate) fn strip_share_label(s: &str) -> Cow<'_, str> {
+    if !s.contains('#') {
+        return Cow::Borrowed(s);
+    }
+    Cow::Owned(
+        s.lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('#'))
+            .collect(),
+    )
+}
+
+/// Share label parsed from the `# shamir v{version} {min}/{total} idx={index}` comment line.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ShareLabel {
+    pub(crate) version: u8,
+    pub(crate) min: usize,
+    pub(crate) total: usize,
+    pub(crate) index: usize,
+}
+
+/// Parses share label from the first comment line of the encoded share.
+///
+#[inline(always)]
+pub(crate) fn parse_share_label(s: &str) -> Result<ShareLabel, SSSError> {
+    let line = s
+        .lines()
+        .map(str::trim)
+        .find(|line| line.starts_with('#'))
+        .ok_or_else(|| SSSError::WithReason("Share has no label line.".to_owned()))?;
+    let malformed = || SSSError::WithReason(format!("Share label '{line}' is malformed."));
+
+    let mut parts = line.trim_start_matches('#').split_whitespace();
+    if parts.next() != Some("shamir") {
+        return Err(malformed());
+    }
+    let version = parts
+        .next()
+        .and_then(|p| p.strip_prefix('v'))
+        .and_then(|p| p.parse().ok())
+        .ok_or_else(malformed)?;
+    let (min, total) = parts
+        .next()
+        .and_then(|p| p.split_once('/'))
+        .and_then(|(m, t)| Some((m.parse().ok()?, t.parse().ok()?)))
+        .ok_or_else(malformed)?;
+    let index = parts
+        .next()
+        .and_then(|p| p.strip_prefix("idx="))
+        .and_then(|p| p.parse().ok())
+        .ok_or_else(malformed)?;
+    if parts.next().is_some() {
+        return Err(malformed());
+    }
+
+    Ok(ShareLabel {
+        version,
+        min,
+        total,
+        index,
+    })
 }
 
 /// Decodes hex shares slice to slices of bytes slices.
+/// Label comment lines are skipped.
 ///
 #[inline(always)]
 pub(crate) fn shares_hex_to_bytes(s: &[String]) -> Result<Vec<Vec<u8>>, SSSError> {
     let mut err = None;
     let result = s
         .iter()
-        .map(hex::decode)
+        .map(|h| hex::decode(strip_hex_separators(&strip_share_label(h)).as_ref()))
         .filter_map(|r| {
             r.map_err(|e| {
                 if err.is_none() {
@@ -117,35 +387,79 @@ pub(crate) fn shares_hex_to_bytes(s: &[String]) -> Result<Vec<Vec<u8>>, SSSError
     Ok(result)
 }
 
-/// Encodes secret bytes to hex.
+/// Encodes secret bytes to lowercase or uppercase hex.
 ///
 #[inline(always)]
-pub(crate) fn secret_bytes_to_hex(h: &[u8]) -> String {
-    hex::encode(h)
+pub(crate) fn secret_bytes_to_hex(h: &[u8], upper: bool) -> String {
+    if upper {
+        hex::encode_upper(h)
+    } else {
+        hex::encode(h)
+    }
 }
 
-/// Encodes shares slices of bytes to hex slices.
+/// Encodes shares slices of bytes to lowercase or uppercase hex slices.
 ///
 #[inline(always)]
-pub(crate) fn shares_bytes_to_hex(h: Vec<Vec<u8>>) -> Vec<String> {
-    h.iter().map(hex::encode).collect::<Vec<String>>()
+pub(crate) fn shares_bytes_to_hex(h: Vec<Vec<u8>>, upper: bool) -> Vec<String> {
+    h.iter()
+        .map(|s| secret_bytes_to_hex(s, upper))
+        .collect::<Vec<String>>()
+}
+
+/// Encodes bytes to lowercase hex split to groups of given hex digits count joined with separator.
+///
+#[inline(always)]
+pub(crate) fn bytes_to_hex_grouped(h: &[u8], group_len: usize, separator: &str) -> String {
+    let encoded = hex::encode(h);
+    if group_len == 0 {
+        return encoded;
+    }
+    encoded
+        .as_bytes()
+        .chunks(group_len)
+        .map(|group| String::from_utf8_lossy(group))
+        .collect::<Vec<_>>()
+        .join(separator)
 }
 
 /// Decodes base64 to bytes.
 ///
 #[inline(always)]
 pub(crate) fn secret_base64_to_bytes(s: &str) -> Result<Vec<u8>, SSSError> {
-    Ok(STANDARD.decode(s)?)
+    secret_base64_to_bytes_with(s, &STANDARD)
+}
+
+/// Decodes base64 to bytes with given engine.
+///
+#[inline(always)]
+pub(crate) fn secret_base64_to_bytes_with(
+    s: &str,
+    engine: &GeneralPurpose,
+) -> Result<Vec<u8>, SSSError> {
+    Ok(engine.decode(s)?)
 }
 
 /// Decodes base64 shares slice to slices of bytes slices.
+/// Label comment lines are skipped.
 ///
 #[inline(always)]
 pub(crate) fn shares_base64_to_bytes(s: &[String]) -> Result<Vec<Vec<u8>>, SSSError> {
+    shares_base64_to_bytes_with(s, &STANDARD)
+}
+
+/// Decodes base64 shares slice to slices of bytes slices with given engine.
+/// Label comment lines are skipped.
+///
+#[inline(always)]
+pub(crate) fn shares_base64_to_bytes_with(
+    s: &[String],
+    engine: &GeneralPurpose,
+) -> Result<Vec<Vec<u8>>, SSSError> {
     let mut err = None;
     let result = s
         .iter()
-        .map(|b| STANDARD.decode(b))
+        .map(|b| engine.decode(strip_share_label(b).as_ref()))
         .filter_map(|r| {
             r.map_err(|e| {
                 if err.is_none() {
@@ -162,6 +476,81 @@ pub(crate) fn shares_base64_to_bytes(s: &[String]) -> Result<Vec<Vec<u8>>, SSSEr
     Ok(result)
 }
 
+/// Base64 config explicitly rejecting non-canonical input, trailing bits and improper padding.
+///
+const STRICT_CONFIG: GeneralPurposeConfig = GeneralPurposeConfig::new()
+    .with_encode_padding(true)
+    .with_decode_allow_trailing_bits(false)
+    .with_decode_padding_mode(DecodePaddingMode::RequireCanonical);
+
+/// Base64 engine explicitly rejecting non-canonical input, trailing bits and improper padding.
+///
+const STRICT_BASE64: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, STRICT_CONFIG);
+
+/// Base64 engine of the URL and filename safe alphabet without padding.
+/// Decoding rejects the standard alphabet '+' and '/' characters, padding and trailing bits.
+///
+pub(crate) const URL_SAFE_BASE64: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, NO_PAD);
+
+/// Returns padded base64 engine for given alphabet.
+///
+#[inline(always)]
+pub(crate) fn custom_base64(alphabet: &alphabet::Alphabet) -> GeneralPurpose {
+    GeneralPurpose::new(alphabet, PAD)
+}
+
+/// Returns base64 engine for given alphabet rejecting non-canonical input.
+///
+#[inline(always)]
+pub(crate) fn custom_base64_strict(alphabet: &alphabet::Alphabet) -> GeneralPurpose {
+    GeneralPurpose::new(alphabet, STRICT_CONFIG)
+}
+
+/// Decodes base64 shares slice to slices of bytes slices rejecting non-canonical encoding.
+///
+#[inline(always)]
+pub(crate) fn shares_base64_to_bytes_strict(s: &[String]) -> Result<Vec<Vec<u8>>, SSSError> {
+    shares_base64_to_bytes_strict_with(s, &STRICT_BASE64)
+}
+
+/// Decodes base64 shares slice to slices of bytes slices with given strict engine.
+///
+#[inline(always)]
+pub(crate) fn shares_base64_to_bytes_strict_with(
+    s: &[String],
+    engine: &GeneralPurpose,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    s.iter().map(|b| Ok(engine.decode(b)?)).collect()
+}
+
+/// Decodes hex shares slice to slices of bytes slices accepting only the canonical lowercase form,
+/// or only the uppercase form when upper is set.
+///
+#[inline(always)]
+pub(crate) fn shares_hex_to_bytes_strict(
+    s: &[String],
+    upper: bool,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    s.iter()
+        .map(|h| {
+            let other_case = |c: &char| {
+                if upper {
+                    c.is_ascii_lowercase()
+                } else {
+                    c.is_ascii_uppercase()
+                }
+            };
+            if let Some((index, c)) = h.char_indices().find(|(_, c)| other_case(c)) {
+                return Err(SSSError::FromHex(hex::FromHexError::InvalidHexCharacter {
+                    c,
+                    index,
+                }));
+            }
+            Ok(hex::decode(h)?)
+        })
+        .collect()
+}
+
 /// Encodes secret bytes to base64.
 ///
 #[inline(always)]
@@ -169,23 +558,317 @@ pub(crate) fn secret_bytes_to_base64(h: &[u8]) -> String {
     STANDARD.encode(h)
 }
 
+/// Encodes secret bytes to base64 with given engine.
+///
+#[inline(always)]
+pub(crate) fn secret_bytes_to_base64_with(h: &[u8], engine: &GeneralPurpose) -> String {
+    engine.encode(h)
+}
+
 /// Encodes secret bytes to base64.
 ///
 #[inline(always)]
 pub(crate) fn shares_bytes_to_base64(h: Vec<Vec<u8>>) -> Vec<String> {
+    shares_bytes_to_base64_with(h, &STANDARD)
+}
+
+/// Encodes shares slices of bytes to base64 slices with given engine.
+///
+#[inline(always)]
+pub(crate) fn shares_bytes_to_base64_with(h: Vec<Vec<u8>>, engine: &GeneralPurpose) -> Vec<String> {
+    h.iter().map(|s| engine.encode(s)).collect::<Vec<String>>()
+}
+
+/// Bitcoin base58 alphabet, without 0, O, I and l characters that are easy to confuse.
+///
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes secret bytes to base58, each leading zero byte is encoded as '1'.
+///
+#[inline(always)]
+pub(crate) fn secret_bytes_to_base58(h: &[u8]) -> String {
+    let zeros = h.iter().take_while(|b| **b == 0).count();
+    // little-endian base58 digits of the number
+    let mut digits: Vec<u8> = Vec::with_capacity(h.len() * 138 / 100 + 1);
+    for byte in h[zeros..].iter() {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut result = String::with_capacity(zeros + digits.len());
+    result.extend(std::iter::repeat('1').take(zeros));
+    result.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|d| BASE58_ALPHABET[*d as usize] as char),
+    );
+    result
+}
+
+/// Decodes base58 to bytes, each leading '1' is decoded as zero byte.
+///
+#[inline(always)]
+pub(crate) fn secret_base58_to_bytes(s: &str) -> Result<Vec<u8>, SSSError> {
+    let zeros = s.bytes().take_while(|c| *c == b'1').count();
+    // little-endian bytes of the number
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len());
+    for (index, c) in s.char_indices().skip(zeros) {
+        let value = BASE58_ALPHABET
+            .iter()
+            .position(|a| *a as char == c)
+            .ok_or(Base58Error::InvalidCharacter { c, index })?;
+        let mut carry = value as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.extend(std::iter::repeat(0).take(zeros));
+    bytes.reverse();
+
+    Ok(bytes)
+}
+
+/// Decodes base58 shares slice to slices of bytes slices.
+/// Label comment lines are skipped.
+///
+#[inline(always)]
+pub(crate) fn shares_base58_to_bytes(s: &[String]) -> Result<Vec<Vec<u8>>, SSSError> {
+    s.iter()
+        .map(|b| secret_base58_to_bytes(strip_share_label(b).as_ref()))
+        .collect()
+}
+
+/// Decodes base58 shares slice to slices of bytes slices, label comment lines are rejected.
+/// Base58 has a single encoding of given bytes, so no other canonical check is needed.
+///
+#[inline(always)]
+pub(crate) fn shares_base58_to_bytes_strict(s: &[String]) -> Result<Vec<Vec<u8>>, SSSError> {
+    s.iter().map(|b| secret_base58_to_bytes(b)).collect()
+}
+
+/// Encodes shares slices of bytes to base58 slices.
+///
+#[inline(always)]
+pub(crate) fn shares_bytes_to_base58(h: Vec<Vec<u8>>) -> Vec<String> {
     h.iter()
-        .map(|s| STANDARD.encode(s))
+        .map(|s| secret_bytes_to_base58(s))
         .collect::<Vec<String>>()
 }
 
+/// RFC 4648 base32 alphabet.
+///
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE32_PAD: char = '=';
+const BASE32_BLOCK: usize = 8;
+
+/// Encodes secret bytes to RFC 4648 base32, padded with '=' to the multiple of 8 characters
+/// if requested.
+///
+#[inline(always)]
+pub(crate) fn secret_bytes_to_base32(h: &[u8], padded: bool) -> String {
+    let mut result = String::with_capacity(h.len().div_ceil(5) * BASE32_BLOCK);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for byte in h.iter() {
+        buffer = (buffer << 8) | *byte as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(BASE32_ALPHABET[(buffer >> bits) as usize & 31] as char);
+        }
+    }
+    if bits > 0 {
+        result.push(BASE32_ALPHABET[(buffer << (5 - bits)) as usize & 31] as char);
+    }
+    if padded {
+        while result.len() % BASE32_BLOCK != 0 {
+            result.push(BASE32_PAD);
+        }
+    }
+    result
+}
+
+/// Decodes RFC 4648 base32 to bytes.
+/// Lowercase letters and missing padding are accepted unless strict decoding is requested.
+/// Strict decoding accepts only the canonical output of secret_bytes_to_base32.
+///
+#[inline(always)]
+fn base32_to_bytes(s: &str, strict: Option<bool>) -> Result<Vec<u8>, SSSError> {
+    let data = s.trim_end_matches(BASE32_PAD);
+    let padding = s.len() - data.len();
+    if !matches!(data.len() % BASE32_BLOCK, 0 | 2 | 4 | 5 | 7) {
+        return Err(Base32Error::InvalidLength(data.len()).into());
+    }
+    let padded = padding > 0 || strict == Some(true);
+    if padded && (s.len() % BASE32_BLOCK != 0 || padding >= BASE32_BLOCK)
+        || strict == Some(false) && padding > 0
+    {
+        return Err(Base32Error::InvalidPadding.into());
+    }
+
+    let mut result = Vec::with_capacity(data.len() * 5 / BASE32_BLOCK);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for (index, c) in data.char_indices() {
+        let lookup = if strict.is_some() {
+            c
+        } else {
+            c.to_ascii_uppercase()
+        };
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a as char == lookup)
+            .ok_or(Base32Error::InvalidCharacter { c, index })?;
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+    if strict.is_some() && buffer & ((1 << bits) - 1) != 0 {
+        return Err(Base32Error::InvalidTrailingBits.into());
+    }
+
+    Ok(result)
+}
+
+/// Decodes RFC 4648 base32 to bytes, padded or not, lowercase letters are accepted.
+///
+#[inline(always)]
+pub(crate) fn secret_base32_to_bytes(s: &str) -> Result<Vec<u8>, SSSError> {
+    base32_to_bytes(s, None)
+}
+
+/// Decodes base32 shares slice to slices of bytes slices.
+/// Label comment lines are skipped.
+///
+#[inline(always)]
+pub(crate) fn shares_base32_to_bytes(s: &[String]) -> Result<Vec<Vec<u8>>, SSSError> {
+    s.iter()
+        .map(|b| secret_base32_to_bytes(strip_share_label(b).as_ref()))
+        .collect()
+}
+
+/// Decodes base32 shares slice to slices of bytes slices rejecting non-canonical encoding.
+/// Lowercase letters, non-zero trailing bits and padding other than requested are rejected.
+///
+#[inline(always)]
+pub(crate) fn shares_base32_to_bytes_strict(
+    s: &[String],
+    padded: bool,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    s.iter().map(|b| base32_to_bytes(b, Some(padded))).collect()
+}
+
+/// Encodes shares slices of bytes to base32 slices.
+///
+#[inline(always)]
+pub(crate) fn shares_bytes_to_base32(h: Vec<Vec<u8>>, padded: bool) -> Vec<String> {
+    h.iter()
+        .map(|s| secret_bytes_to_base32(s, padded))
+        .collect::<Vec<String>>()
+}
+
+/// Reads shares framed as 4 bytes big-endian length followed by the share bytes until EOF.
+/// EOF is accepted only at the frame boundary, truncated length or body is an error.
+///
+#[inline(always)]
+pub(crate) fn read_framed_shares<R: Read>(mut reader: R) -> Result<Vec<Vec<u8>>, SSSError> {
+    let mut shares = Vec::new();
+    loop {
+        let mut length = [0u8; 4];
+        let mut filled = 0;
+        while filled < length.len() {
+            match reader.read(&mut length[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        if filled == 0 {
+            return Ok(shares);
+        }
+        if filled < length.len() {
+            return Err(SSSError::WithReason(format!(
+                "Share frame {} has truncated length.",
+                shares.len()
+            )));
+        }
+
+        let length = u32::from_be_bytes(length) as usize;
+        let mut share = Vec::new();
+        (&mut reader).take(length as u64).read_to_end(&mut share)?;
+        if share.len() < length {
+            return Err(SSSError::WithReason(format!(
+                "Share frame {} has truncated body, expected {length} bytes, got {}.",
+                shares.len(),
+                share.len()
+            )));
+        }
+        shares.push(share);
+    }
+}
+
+/// Size in bytes of the CRC32 checksum appended to checked shares.
+///
+pub(crate) const CHECKSUM_SIZE: usize = 4;
+
+/// Reflected polynomial of the IEEE 802.3 CRC32.
+///
+const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+
+/// Calculates the IEEE 802.3 CRC32 of given bytes.
+///
+#[inline(always)]
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for b in bytes.iter() {
+        crc ^= *b as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (CRC32_POLYNOMIAL & mask);
+        }
+    }
+    !crc
+}
+
 #[cfg(test)]
 mod tests {
     use openssl::bn::BigNumContext;
+    use openssl::rand::rand_bytes;
 
     use super::*;
+    use crate::rng::OpensslRng;
     use std::collections::HashSet;
     use std::time::Instant;
 
+    #[test]
+    fn it_should_calculate_ieee_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(
+            crc32(b"The quick brown fox jumps over the lazy dog"),
+            0x414F_A339
+        );
+    }
+
     const ITER_COUNT: usize = 1_000_000;
     const BENCH_ITTER: usize = 100_000;
 
@@ -200,6 +883,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_should_never_return_zero_from_random_nonzero() -> Result<(), SSSError> {
+        let two = BigNum::from_u32(2)?;
+        for _ in 0..1000 {
+            assert_eq!(random_nonzero(&mut OpensslRng, &two)?, BigNum::from_u32(1)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_clear_big_nums_and_wipe_bytes() -> Result<(), SSSError> {
+        let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+        let mut nums = vec![
+            (random(&prime)?, random(&prime)?),
+            (random(&prime)?, random(&prime)?),
+        ];
+        nums.clear_big_nums();
+        assert!(nums
+            .iter()
+            .all(|(a, b)| a.num_bits() == 0 && b.num_bits() == 0));
+
+        let mut guarded = ClearOnDrop(vec![BigNum::from_u32(7)?]);
+        guarded.push(BigNum::from_u32(9)?);
+        assert_eq!(big_nums_to_bytes(&guarded)[31], 7);
+
+        let mut bytes = vec![0xff; 64];
+        wipe_bytes(&mut bytes);
+        assert_eq!(bytes, vec![0; 64]);
+
+        Ok(())
+    }
+
     #[test]
     fn it_should_benchmark_random_big_int() -> Result<(), SSSError> {
         let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
@@ -409,7 +1125,7 @@ mod tests {
             expected: &'a [BigNum],
         }
 
-        for c in vec![
+        for c in [
             TestCase {
                 name: "bytes_2_bytes",
                 buff: bytes_2_bytes,
@@ -594,6 +1310,215 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_should_reject_non_canonical_base64_in_strict_decoding() -> Result<(), SSSError> {
+        let canonical = vec!["AA==".to_string(), "AAE=".to_string()];
+        assert_eq!(
+            shares_base64_to_bytes_strict(&canonical)?,
+            vec![vec![0], vec![0, 1]]
+        );
+
+        for non_canonical in ["AB==", "AAF=", "AA", "AA=", "AA==="] {
+            let result = shares_base64_to_bytes_strict(&[non_canonical.to_string()]);
+            assert!(
+                matches!(result, Err(SSSError::FromBase64(_))),
+                "{non_canonical} shall be rejected"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_non_canonical_hex_in_strict_decoding() -> Result<(), SSSError> {
+        assert_eq!(
+            shares_hex_to_bytes_strict(&["0aff".to_string()], false)?,
+            vec![vec![10, 255]]
+        );
+        let result = shares_hex_to_bytes_strict(&["0aFf".to_string()], false);
+        assert!(matches!(result, Err(SSSError::FromHex(_))));
+        assert_eq!(
+            shares_hex_to_bytes_strict(&["0AFF".to_string()], true)?,
+            vec![vec![10, 255]]
+        );
+        let result = shares_hex_to_bytes_strict(&["0aFF".to_string()], true);
+        assert!(matches!(result, Err(SSSError::FromHex(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_encode_and_decode_base58() -> Result<(), SSSError> {
+        for (bytes, encoded) in [
+            (b"Hello World!".to_vec(), "2NEpo7TZRRrLZSi2U"),
+            (vec![0, 0, 0x28, 0x7f, 0xb4, 0xcd], "11233QC4"),
+            (vec![0, 0], "11"),
+            (vec![], ""),
+        ] {
+            assert_eq!(secret_bytes_to_base58(&bytes), encoded);
+            assert_eq!(secret_base58_to_bytes(encoded)?, bytes);
+        }
+
+        for size in [1, 32, 64, 128, 512] {
+            let mut bytes = vec![0; size];
+            rand_bytes(&mut bytes)?;
+            bytes[0] = 0;
+            let encoded = secret_bytes_to_base58(&bytes);
+            assert_eq!(secret_base58_to_bytes(&encoded)?, bytes);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_invalid_base58_characters() {
+        for (encoded, c, index) in [("2NEpo0", '0', 5), ("1Il", 'I', 1), ("ab+c", '+', 2)] {
+            match secret_base58_to_bytes(encoded) {
+                Err(SSSError::FromBase58(e)) => {
+                    assert_eq!(e, Base58Error::InvalidCharacter { c, index })
+                }
+                _ => panic!("expected base58 error"),
+            }
+        }
+        assert!(shares_base58_to_bytes_strict(&["# label\n2NEpo".to_string()]).is_err());
+        assert!(shares_base58_to_bytes(&["# label\n2NEpo".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn it_should_encode_and_decode_base32() -> Result<(), SSSError> {
+        for (bytes, encoded) in [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ] {
+            assert_eq!(secret_bytes_to_base32(bytes.as_bytes(), true), encoded);
+            let unpadded = encoded.trim_end_matches('=');
+            assert_eq!(secret_bytes_to_base32(bytes.as_bytes(), false), unpadded);
+            for input in [encoded, unpadded] {
+                assert_eq!(secret_base32_to_bytes(input)?, bytes.as_bytes());
+                assert_eq!(
+                    secret_base32_to_bytes(&input.to_ascii_lowercase())?,
+                    bytes.as_bytes()
+                );
+            }
+        }
+
+        for size in [32, 128, 512] {
+            let mut bytes = vec![0; size];
+            rand_bytes(&mut bytes)?;
+            for padded in [true, false] {
+                let encoded = vec![secret_bytes_to_base32(&bytes, padded)];
+                assert_eq!(shares_base32_to_bytes(&encoded)?, vec![bytes.clone()]);
+                assert_eq!(
+                    shares_base32_to_bytes_strict(&encoded, padded)?,
+                    vec![bytes.clone()]
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_invalid_and_non_canonical_base32() {
+        for (encoded, error) in [
+            (
+                "MZXW1===",
+                Base32Error::InvalidCharacter { c: '1', index: 4 },
+            ),
+            ("MZX", Base32Error::InvalidLength(3)),
+            ("MZXQ==", Base32Error::InvalidPadding),
+            ("MZXW6YTB========", Base32Error::InvalidPadding),
+        ] {
+            match secret_base32_to_bytes(encoded) {
+                Err(SSSError::FromBase32(e)) => assert_eq!(e, error),
+                _ => panic!("expected base32 error"),
+            }
+        }
+
+        for (encoded, padded) in [
+            ("mzxq====", true),
+            ("MZXR====", true),
+            ("MZXQ", true),
+            ("MZXQ====", false),
+        ] {
+            assert!(shares_base32_to_bytes_strict(&[encoded.to_string()], padded).is_err());
+        }
+    }
+
+    #[test]
+    fn it_should_read_framed_shares_until_eof() -> Result<(), SSSError> {
+        let mut framed = Vec::new();
+        for share in [vec![1; 3], vec![], vec![2; 5]] {
+            framed.extend((share.len() as u32).to_be_bytes());
+            framed.extend(share);
+        }
+        let shares = read_framed_shares(std::io::Cursor::new(&framed))?;
+        assert_eq!(shares, vec![vec![1; 3], vec![], vec![2; 5]]);
+        assert!(read_framed_shares(std::io::Cursor::new(&[]))?.is_empty());
+
+        assert!(read_framed_shares(std::io::Cursor::new(&framed[..framed.len() - 1])).is_err());
+        assert!(read_framed_shares(std::io::Cursor::new(&framed[..9])).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_parse_share_label() -> Result<(), SSSError> {
+        let label = parse_share_label("# shamir v1 3/5 idx=2\n00ff")?;
+        assert_eq!(
+            label,
+            ShareLabel {
+                version: 1,
+                min: 3,
+                total: 5,
+                index: 2
+            }
+        );
+        assert!(parse_share_label("00ff").is_err());
+        assert!(parse_share_label("# shamir v1 3-5 idx=2\n00ff").is_err());
+        assert!(parse_share_label("# shamir v1 3/5 idx=2 extra\n00ff").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_evaluate_with_scratch_arena_same_as_naive_evaluation() -> Result<(), SSSError> {
+        let mut ctx = BigNumContext::new()?;
+        let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+        let mut scratch = ScratchArena::new(1)?;
+        for _ in 0..100 {
+            let slice = (0..5)
+                .map(|_| random(&prime))
+                .collect::<Result<Vec<BigNum>, _>>()?;
+            let value = random(&prime)?;
+
+            let mut expected = BigNum::new()?;
+            let mut power = BigNum::from_u32(1)?;
+            let mut term = BigNum::new()?;
+            for coefficient in slice.iter() {
+                term.mod_mul(coefficient, &power, &prime, &mut ctx)?;
+                let mut next = BigNum::new()?;
+                next.mod_add(&expected, &term, &prime, &mut ctx)?;
+                expected = next;
+                term.mod_mul(&power, &value, &prime, &mut ctx)?;
+                std::mem::swap(&mut power, &mut term);
+            }
+
+            let result = evaluate_with(&mut ctx, &mut scratch, &slice, &value, &prime)?;
+            assert_eq!(result, expected);
+        }
+
+        scratch.reset();
+        assert_eq!(scratch.take::<1>()[0].num_bits(), 0);
+
+        Ok(())
+    }
+
     #[test]
     fn it_should_evaluate_the_polynomial() -> Result<(), SSSError> {
         struct TestCase<'a> {
@@ -640,10 +1565,11 @@ mod tests {
 
         let mut ctx = BigNumContext::new().unwrap();
         let prime = BigNum::from_dec_str(DEFAULT_PRIME).unwrap();
+        let mut scratch = ScratchArena::new(1)?;
 
         for c in test_cases.iter() {
             println!("Testing {}", c.name);
-            let actual = evaluate(&mut ctx, c.slice, &c.value, &prime)?;
+            let actual = evaluate_with(&mut ctx, &mut scratch, c.slice, &c.value, &prime)?;
             assert!(actual.eq(&c.actual));
         }
 
@@ -669,10 +1595,11 @@ mod tests {
         let value = BigNum::from_dec_str("0")?;
         let mut ctx = BigNumContext::new()?;
         let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+        let mut scratch = ScratchArena::new(1)?;
         let now = Instant::now();
 
         for _ in 0..BENCH_ITTER {
-            let _ = evaluate(&mut ctx, slice, &value, &prime);
+            let _ = evaluate_with(&mut ctx, &mut scratch, slice, &value, &prime);
         }
 
         println!(
@@ -0,0 +1,186 @@
+use crate::{
+    errors::SSSError,
+    operations::{crc32, secret_bytes_to_base64, shares_base64_to_bytes_strict},
+    shamirss::MIN_SHARES_COUNT,
+};
+
+const PEM_BEGIN: &str = "-----BEGIN SHAMIR SHARE-----";
+const PEM_END: &str = "-----END SHAMIR SHARE-----";
+const PEM_LINE_LENGTH: usize = 64;
+
+/// ShareMeta holds the share metadata carried in the PEM headers.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShareMeta {
+    /// Share index starting from 1.
+    pub index: usize,
+    pub min: usize,
+    pub total: usize,
+}
+
+/// Encodes share to a PEM armored block with the share index, threshold and CRC32 checksum of the
+/// share in headers, followed by the base64 share wrapped at 64 characters.
+///
+/// # Argument
+///
+/// * `share`  - share bytes.
+/// * `index`  - share index starting from 1.
+/// * `min`    - minimum shares amount needed to recreate the secret.
+/// * `total`  - total shares amount.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{create_std, decode_share_pem, encode_share_pem};
+///
+///let shares = create_std(2, 3, &[7; 32]).unwrap();
+///let pem = encode_share_pem(&shares[0], 1, 2, 3);
+///assert!(pem.starts_with("-----BEGIN SHAMIR SHARE-----"));
+///let (share, meta) = decode_share_pem(&pem).unwrap();
+///assert_eq!(share, shares[0]);
+///assert_eq!((meta.index, meta.min, meta.total), (1, 2, 3));
+///```
+///
+pub fn encode_share_pem(share: &[u8], index: usize, min: usize, total: usize) -> String {
+    let encoded = secret_bytes_to_base64(share);
+    let mut pem = format!(
+        "{PEM_BEGIN}\nIndex: {index}\nThreshold: {min}/{total}\nChecksum: {:08x}\n\n",
+        crc32(share)
+    );
+    for line in encoded.as_bytes().chunks(PEM_LINE_LENGTH) {
+        pem.push_str(&String::from_utf8_lossy(line));
+        pem.push('\n');
+    }
+    pem.push_str(PEM_END);
+    pem.push('\n');
+    pem
+}
+
+/// Decodes share and its metadata from a PEM armored block created with encode_share_pem.
+/// Share not matching the checksum header, or metadata out of range, is rejected.
+///
+/// # Argument
+///
+/// * `s`  - PEM armored share.
+///
+pub fn decode_share_pem(s: &str) -> Result<(Vec<u8>, ShareMeta), SSSError> {
+    let invalid = |reason: &str| SSSError::WithReason(format!("Invalid share PEM: {reason}."));
+    let mut lines = s.lines().map(str::trim).skip_while(|line| line.is_empty());
+    if lines.next() != Some(PEM_BEGIN) {
+        return Err(invalid("missing begin line"));
+    }
+
+    let (mut index, mut threshold, mut checksum) = (None, None, None);
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| invalid("malformed header"))?;
+        let value = value.trim();
+        match name.trim() {
+            "Index" => index = value.parse::<usize>().ok(),
+            "Threshold" => {
+                threshold = value.split_once('/').and_then(|(min, total)| {
+                    Some((min.parse::<usize>().ok()?, total.parse::<usize>().ok()?))
+                })
+            }
+            "Checksum" => checksum = u32::from_str_radix(value, 16).ok(),
+            _ => return Err(invalid("unknown header")),
+        }
+    }
+    let index = index.ok_or_else(|| invalid("missing or malformed Index header"))?;
+    let (min, total) = threshold.ok_or_else(|| invalid("missing or malformed Threshold header"))?;
+    let checksum = checksum.ok_or_else(|| invalid("missing or malformed Checksum header"))?;
+    if index == 0 || index > total || min < MIN_SHARES_COUNT || min > total {
+        return Err(invalid("metadata out of range"));
+    }
+
+    let mut body = String::new();
+    let mut closed = false;
+    for line in lines.by_ref() {
+        if line == PEM_END {
+            closed = true;
+            break;
+        }
+        body.push_str(line);
+    }
+    if !closed {
+        return Err(invalid("missing end line"));
+    }
+    if lines.any(|line| !line.is_empty()) {
+        return Err(invalid("data after end line"));
+    }
+
+    let share = shares_base64_to_bytes_strict(&[body])?.remove(0);
+    if crc32(&share) != checksum {
+        return Err(invalid("checksum mismatch"));
+    }
+
+    Ok((share, ShareMeta { index, min, total }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shamirss::{combine_shares, create_shares};
+
+    #[test]
+    fn it_should_round_trip_shares_through_pem() -> Result<(), SSSError> {
+        let secret = vec![4; 96];
+        let shares = create_shares(3, 5, &secret)?;
+        let pems: Vec<String> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, share)| encode_share_pem(share, i + 1, 3, 5))
+            .collect();
+        assert!(pems[0]
+            .lines()
+            .all(|line| line.len() <= PEM_LINE_LENGTH || line.starts_with("-----")));
+
+        let mut decoded = Vec::new();
+        for (i, pem) in pems.iter().enumerate().skip(2) {
+            let (share, meta) = decode_share_pem(pem)?;
+            assert_eq!(share, shares[i]);
+            assert_eq!(
+                meta,
+                ShareMeta {
+                    index: i + 1,
+                    min: 3,
+                    total: 5
+                }
+            );
+            decoded.push(share);
+        }
+        assert_eq!(combine_shares(decoded)?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_corrupted_pem() -> Result<(), SSSError> {
+        let shares = create_shares(2, 3, &[4; 64])?;
+        let pem = encode_share_pem(&shares[1], 2, 2, 3);
+
+        let body_line = pem.lines().nth(5).unwrap_or_default().to_owned();
+        let mut corrupted_line = body_line.clone().into_bytes();
+        corrupted_line[10] = if corrupted_line[10] == b'A' {
+            b'B'
+        } else {
+            b'A'
+        };
+        let corrupted = pem.replacen(&body_line, &String::from_utf8_lossy(&corrupted_line), 1);
+        match decode_share_pem(&corrupted) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("checksum mismatch")),
+            other => panic!("expected checksum mismatch, got {other:?}"),
+        }
+
+        assert!(decode_share_pem(&pem.replacen(&body_line[..4], "!!!!", 1)).is_err());
+        assert!(decode_share_pem(&pem.replace(PEM_END, "")).is_err());
+        assert!(decode_share_pem(&pem.replace("Index: 2", "Index: 4")).is_err());
+        assert!(decode_share_pem(&pem[PEM_BEGIN.len()..]).is_err());
+
+        Ok(())
+    }
+}
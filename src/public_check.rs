@@ -0,0 +1,104 @@
+use crate::{
+    errors::SSSError,
+    operations::{is_proper_size, U8S_TO_BIG_INT_INITIAL},
+    shamirss::create_shares,
+};
+use openssl::{memcmp, rand::rand_bytes, sha::Sha256};
+
+const SALT_SIZE: usize = 32;
+
+/// PublicCheck holds salted SHA-256 digest of the secret, which can be stored publicly
+/// next to the shares to verify the reconstructed secret.
+/// Digest of a low entropy secret can be brute forced, so it shall be used for random secrets
+/// such as keys only.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicCheck {
+    pub salt: Vec<u8>,
+    pub digest: Vec<u8>,
+}
+
+/// Creates shares from given secret together with the public check of the secret.
+/// Polynomial evaluations at public points cannot be checked against the secret alone, as the
+/// other coefficients are random, so the check commits to the secret with a salted digest.
+///
+/// # Argument
+///
+/// * `min`     - minimal amount of shares required to recreate the secret.
+/// * `total`   - total amount of shares.
+/// * `secret`  - secret to be shared, divisible by 32 without rest.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std, create_shares_with_public_check, verify_with_public_check};
+///
+///let secret = vec![7; 64];
+///let (shares, check) = create_shares_with_public_check(3, 5, &secret).unwrap();
+///let secret_recreated = combine_std(shares[..3].to_vec()).unwrap();
+///assert!(verify_with_public_check(&secret_recreated, &check));
+///```
+///
+pub fn create_shares_with_public_check(
+    min: usize,
+    total: usize,
+    secret: &[u8],
+) -> Result<(Vec<Vec<u8>>, PublicCheck), SSSError> {
+    if !is_proper_size(secret) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
+    }
+    let shares = create_shares(min, total, secret)?;
+
+    let mut salt = vec![0; SALT_SIZE];
+    rand_bytes(&mut salt)?;
+    let digest = salted_digest(&salt, secret);
+
+    Ok((shares, PublicCheck { salt, digest }))
+}
+
+/// Verifies reconstructed secret against the public check in constant time.
+///
+/// # Argument
+///
+/// * `reconstructed`  - secret recreated from shares.
+/// * `check`          - public check created together with the shares.
+///
+pub fn verify_with_public_check(reconstructed: &[u8], check: &PublicCheck) -> bool {
+    let digest = salted_digest(&check.salt, reconstructed);
+    digest.len() == check.digest.len() && memcmp::eq(&digest, &check.digest)
+}
+
+#[inline(always)]
+fn salted_digest(salt: &[u8], secret: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(salt);
+    hasher.update(secret);
+    hasher.finish().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shamirss::combine_shares;
+
+    #[test]
+    fn it_should_verify_reconstruction_with_public_check() -> Result<(), SSSError> {
+        let mut secret = vec![0; 64];
+        rand_bytes(&mut secret)?;
+
+        let (shares, check) = create_shares_with_public_check(3, 5, &secret)?;
+        let recreated = combine_shares(shares[2..].to_vec())?;
+        assert!(verify_with_public_check(&recreated, &check));
+
+        let too_few = combine_shares(shares[..2].to_vec())?;
+        assert!(!verify_with_public_check(&too_few, &check));
+
+        let (_, other_check) = create_shares_with_public_check(3, 5, &secret)?;
+        assert_ne!(check.salt, other_check.salt);
+
+        Ok(())
+    }
+}
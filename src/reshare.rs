@@ -0,0 +1,61 @@
+use crate::{
+    errors::SSSError,
+    operations::wipe_bytes,
+    shamirss::{combine_shares, create_shares},
+};
+
+/// Creates shares of the secret of given shares under a new threshold.
+/// Secret is recreated internally and overwritten with zeros right after the new shares are
+/// created, whether creating them succeeded or not. All given shares are combined, so they
+/// shall be equal or more the minimal share count the old shares were created with.
+///
+/// # Argument
+///
+/// * `old_shares`  - vector of shares under the old threshold.
+/// * `new_min`     - minimum shares amount needed to recreate the secret from new shares.
+/// * `new_total`   - total new shares amount to create.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std, create_std, reshare};
+///
+///let secret = vec![7; 64];
+///let old_shares: Vec<Vec<u8>> = create_std(2, 3, &secret).unwrap();
+///let new_shares = reshare(old_shares[..2].to_vec(), 3, 5).unwrap();
+///assert_eq!(secret, combine_std(new_shares[2..].to_vec()).unwrap());
+///```
+///
+pub fn reshare(
+    old_shares: Vec<Vec<u8>>,
+    new_min: usize,
+    new_total: usize,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    let mut secret = combine_shares(old_shares)?;
+    let shares = create_shares(new_min, new_total, &secret);
+    wipe_bytes(&mut secret);
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::rand::rand_bytes;
+
+    #[test]
+    fn it_should_reshare_under_new_threshold() -> Result<(), SSSError> {
+        let mut secret = vec![0; 96];
+        rand_bytes(&mut secret)?;
+        let old_shares = create_shares(4, 5, &secret)?;
+
+        let new_shares = reshare(old_shares[1..].to_vec(), 6, 10)?;
+        assert_eq!(new_shares.len(), 10);
+        assert_eq!(combine_shares(new_shares[..6].to_vec())?, secret);
+        assert_eq!(combine_shares(new_shares[4..].to_vec())?, secret);
+        assert_ne!(combine_shares(new_shares[..4].to_vec())?, secret);
+
+        assert!(reshare(old_shares, 11, 10).is_err());
+
+        Ok(())
+    }
+}
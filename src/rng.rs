@@ -0,0 +1,24 @@
+use crate::{errors::SSSError, operations::random};
+use openssl::bn::BigNum;
+
+/// ShareRng is a source of random numbers used for polynomial coefficients and x-coordinates
+/// when creating shares. Implementation shall be cryptographically secure, shares created with
+/// predictable numbers reveal the secret.
+///
+pub trait ShareRng {
+    /// Returns random number between 0 and upper - 1.
+    ///
+    fn gen_below(&mut self, upper: &BigNum) -> Result<BigNum, SSSError>;
+}
+
+/// OpensslRng draws random numbers from Openssl cryptographically secure generator.
+/// Used by all the functions creating shares unless other ShareRng is given.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OpensslRng;
+
+impl ShareRng for OpensslRng {
+    fn gen_below(&mut self, upper: &BigNum) -> Result<BigNum, SSSError> {
+        random(upper)
+    }
+}
@@ -1,57 +1,1303 @@
 use crate::{
+    config::SplitConfig,
     errors::SSSError,
     operations::{
-        big_nums_to_bytes, bytes_to_big_nums, evaluate, random, DEFAULT_PRIME,
+        big_nums_to_bytes, bytes_to_big_nums, default_prime, evaluate_with, legacy_prime,
+        random_nonzero, refresh_context, wipe_bytes, ClearOnDrop, ScratchArena,
         U8S_TO_BIG_INT_INITIAL,
     },
+    rng::{OpensslRng, ShareRng},
 };
-use openssl::bn::{BigNum, BigNumContext};
-const COEFFICIENTS_PER_SHARE: usize = 2;
-const COEFFICIENTS_SIZE: usize = COEFFICIENTS_PER_SHARE * U8S_TO_BIG_INT_INITIAL;
+use openssl::bn::{BigNum, BigNumContext, BigNumContextRef};
+use std::{collections::HashSet, time::Instant};
+pub(crate) const COEFFICIENTS_PER_SHARE: usize = 2;
+pub(crate) const COEFFICIENTS_SIZE: usize = COEFFICIENTS_PER_SHARE * U8S_TO_BIG_INT_INITIAL;
+pub(crate) const MIN_SHARES_COUNT: usize = 2;
+const INDEX_SIZE: usize = 1;
+
+/// Version of the share format written as the very first byte of every created share.
+/// Shares without the version byte are legacy shares and are treated as version 0.
+/// Version 2 shares are calculated over the prime above 2^256, older shares over the prime
+/// below 2^256, so shares of version 2 cannot be combined with older shares.
+///
+pub(crate) const SHARE_FORMAT_VERSION: u8 = 2;
+const LEGACY_PRIME_FORMAT_VERSION: u8 = 1;
+pub(crate) const SHARE_HEADER_SIZE: usize = 1;
+
+/// Version of the share format carrying the minimum shares count in the second header byte.
+/// Share body is the same as of version 2 shares, calculated over the prime above 2^256.
+///
+pub(crate) const THRESHOLD_FORMAT_VERSION: u8 = 3;
+const THRESHOLD_HEADER_SIZE: usize = 2;
+
+/// Multi-secret shares start with the share format version byte followed by the secrets count
+/// byte and are calculated over the prime above 2^256. Legacy multi-secret shares start with the
+/// secrets count byte only and are calculated over the prime below 2^256, so the header size is
+/// told apart by the share size modulo U8S_TO_BIG_INT_INITIAL.
+///
+const MULTISECRET_HEADER_SIZE: usize = 2;
+const LEGACY_MULTISECRET_HEADER_SIZE: usize = 1;
+
+/// Shares count squared shifted by this many bits shall stay below the prime.
+/// Keeps the birthday bound probability of x-coordinates collision below 2^-32.
+///
+const X_COLLISION_SAFETY_BITS: i32 = 32;
+const LAGRANGE_SCRATCH: usize = 5;
+
+/// Returns share body stripped from the format header.
+/// Share which size is divisible by COEFFICIENTS_SIZE has no header and is a legacy (version 0)
+/// share, otherwise the first byte is read as format version.
+/// Share body without any chunk is rejected, as it carries no secret data.
+///
+#[inline(always)]
+pub(crate) fn share_body(share: &[u8]) -> Result<&[u8], SSSError> {
+    let body = if share.len() % COEFFICIENTS_SIZE == 0 {
+        share
+    } else {
+        match share[0] {
+            SHARE_FORMAT_VERSION | LEGACY_PRIME_FORMAT_VERSION => &share[SHARE_HEADER_SIZE..],
+            THRESHOLD_FORMAT_VERSION => share.get(THRESHOLD_HEADER_SIZE..).unwrap_or_default(),
+            version => {
+                return Err(SSSError::WithReason(format!(
+                    "unsupported share format version {version}"
+                )))
+            }
+        }
+    };
+    if body.len() % COEFFICIENTS_SIZE != 0 {
+        return Err(SSSError::ShareNotAligned {
+            len: body.len(),
+            block: COEFFICIENTS_SIZE,
+        });
+    }
+    if body.is_empty() {
+        return Err(SSSError::WithReason(
+            "share contains no secret data after header".to_owned(),
+        ));
+    }
+
+    Ok(body)
+}
+
+/// Returns share format version, legacy shares without the version byte are version 0.
+///
+#[inline(always)]
+pub(crate) fn share_version(share: &[u8]) -> Result<u8, SSSError> {
+    if share_body(share)?.len() == share.len() {
+        return Ok(0);
+    }
+    Ok(share[0])
+}
+
+/// Returns prime the shares were created with, selected by the share format version.
+/// Shares of version 2 or 3 cannot be mixed with legacy or version 1 shares.
+///
+#[inline(always)]
+pub(crate) fn shares_prime(shares: &[Vec<u8>]) -> Result<&'static BigNum, SSSError> {
+    let mut current_format = None;
+    for share in shares.iter() {
+        let is_current = matches!(
+            share_version(share)?,
+            SHARE_FORMAT_VERSION | THRESHOLD_FORMAT_VERSION
+        );
+        match current_format {
+            Some(current) if current != is_current => {
+                return Err(SSSError::WithReason(format!(
+                    "Shares of format version {SHARE_FORMAT_VERSION} cannot be combined with shares of older format versions."
+                )));
+            }
+            _ => current_format = Some(is_current),
+        }
+    }
+
+    match current_format {
+        Some(false) => legacy_prime(),
+        _ => default_prime(),
+    }
+}
 
 /// Crates shares from given secret.
+/// Each share starts with the SHARE_FORMAT_VERSION byte followed by the share body.
+/// Function uses Openssl library for cryptographically secure pseudo-random number generation and
+/// BigNum from Openssl big num package to calculate coefficients up to 64 bytes in size.
+///
+#[inline(always)]
+pub(crate) fn create_shares(
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    create_shares_with_prime(min, shares, secret, default_prime()?)
+}
+
+/// Crates shares from given secret using given prime for mod calculations.
+/// Shares count is validated against the prime so random x-coordinates are unlikely to collide,
+/// x-coordinates colliding anyway are drawn again, so every chunk has distinct x in all shares.
+///
+#[inline(always)]
+pub(crate) fn create_shares_with_prime(
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+    prime: &BigNum,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    let mut ctx = BigNumContext::new()?;
+    create_shares_with_context(&mut ctx, &mut OpensslRng, min, shares, secret, prime)
+}
+
+/// Crates shares from given secret using given prime and big num context for mod calculations.
+/// Lets the caller reuse a single context when creating shares for many secrets.
+/// Polynomial coefficients and x-coordinates are drawn from given random numbers source.
+///
+#[inline(always)]
+pub(crate) fn create_shares_with_context<R: ShareRng>(
+    ctx: &mut BigNumContext,
+    rng: &mut R,
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+    prime: &BigNum,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    let mut used: Vec<HashSet<Vec<u8>>> =
+        vec![HashSet::with_capacity(shares); secret.len().div_ceil(U8S_TO_BIG_INT_INITIAL)];
+    create_shares_with_xs(ctx, rng, min, shares, secret, prime, |rng, _, chunk| {
+        distinct_random_x(rng, &mut used[chunk], prime)
+    })
+}
+
+/// Crates version 1 shares over the legacy prime below 2^256, skipping the prime size validation
+/// rejecting the legacy prime for new shares. Lets tests create shares of the older format.
+///
+#[cfg(test)]
+pub(crate) fn create_shares_with_legacy_prime(
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    let mut ctx = BigNumContext::new()?;
+    let prime = legacy_prime()?;
+    let polynomial = secret_polynomial(&mut OpensslRng, min, secret, prime)?;
+    let mut used: Vec<HashSet<Vec<u8>>> = vec![HashSet::with_capacity(shares); polynomial.len()];
+    let mut results = create_shares_from_polynomial(
+        &mut ctx,
+        &mut OpensslRng,
+        &polynomial,
+        shares,
+        prime,
+        |rng, _, chunk| distinct_random_x(rng, &mut used[chunk], prime),
+    )?;
+    for share in results.iter_mut() {
+        share[0] = LEGACY_PRIME_FORMAT_VERSION;
+    }
+
+    Ok(results)
+}
+
+/// Returns random non-zero x-coordinate not present in used x-coordinates and records it.
+///
+#[inline(always)]
+fn distinct_random_x<R: ShareRng>(
+    rng: &mut R,
+    used: &mut HashSet<Vec<u8>>,
+    prime: &BigNum,
+) -> Result<BigNum, SSSError> {
+    loop {
+        let coefficient_x = random_nonzero(rng, prime)?;
+        if used.insert(coefficient_x.to_vec()) {
+            return Ok(coefficient_x);
+        }
+    }
+}
+
+/// Crates shares from given secret taking x-coordinates from given source.
+/// Source is called with random numbers source, share index and chunk index for every chunk of
+/// every share, which lets tests supply deterministic x-coordinates.
+///
+#[inline(always)]
+pub(crate) fn create_shares_with_xs<R: ShareRng, F>(
+    ctx: &mut BigNumContext,
+    rng: &mut R,
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+    prime: &BigNum,
+    next_x: F,
+) -> Result<Vec<Vec<u8>>, SSSError>
+where
+    F: FnMut(&mut R, usize, usize) -> Result<BigNum, SSSError>,
+{
+    validate_create_args(ctx, min, shares, prime)?;
+    let polynomial = secret_polynomial(rng, min, secret, prime)?;
+    create_shares_from_polynomial(ctx, rng, &polynomial, shares, prime, next_x)
+}
+
+/// Crates shares from the default prime polynomials of given secret, passing the polynomials to
+/// given function before the shares are created, which lets the caller commit to coefficients.
+/// Coefficients are cleared when shares are created, only the result of the function is kept.
+///
+#[inline(always)]
+pub(crate) fn create_shares_with_polynomial<C, F>(
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+    inspect: F,
+) -> Result<(Vec<Vec<u8>>, C), SSSError>
+where
+    F: FnOnce(&mut BigNumContext, &[Vec<BigNum>]) -> Result<C, SSSError>,
+{
+    let mut ctx = BigNumContext::new()?;
+    let prime = default_prime()?;
+    validate_create_args(&mut ctx, min, shares, prime)?;
+    let polynomial = secret_polynomial(&mut OpensslRng, min, secret, prime)?;
+    let inspected = inspect(&mut ctx, &polynomial)?;
+
+    let mut used: Vec<HashSet<Vec<u8>>> = vec![HashSet::with_capacity(shares); polynomial.len()];
+    let results = create_shares_from_polynomial(
+        &mut ctx,
+        &mut OpensslRng,
+        &polynomial,
+        shares,
+        prime,
+        |rng, _, chunk| distinct_random_x(rng, &mut used[chunk], prime),
+    )?;
+
+    Ok((results, inspected))
+}
+
+/// Validates shares amounts and prime used to create shares.
+///
+#[inline(always)]
+pub(crate) fn validate_create_args(
+    ctx: &mut BigNumContext,
+    min: usize,
+    shares: usize,
+    prime: &BigNum,
+) -> Result<(), SSSError> {
+    if min > shares {
+        return Err(SSSError::MinGreaterThanShares { min, shares });
+    }
+    if min < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "Minimum value cannot be smaller then {MIN_SHARES_COUNT}."
+        )));
+    }
+
+    validate_x_collision_risk(ctx, shares, prime)?;
+    validate_prime_size(prime)
+}
+
+/// Crates shares evaluating given chunk polynomials at x-coordinates taken from given source.
+///
+#[inline(always)]
+fn create_shares_from_polynomial<R: ShareRng, F>(
+    ctx: &mut BigNumContext,
+    rng: &mut R,
+    polynomial: &[Vec<BigNum>],
+    shares: usize,
+    prime: &BigNum,
+    mut next_x: F,
+) -> Result<Vec<Vec<u8>>, SSSError>
+where
+    F: FnMut(&mut R, usize, usize) -> Result<BigNum, SSSError>,
+{
+    let mut results: Vec<Vec<u8>> = Vec::with_capacity(shares);
+    let mut scratch = ScratchArena::new(1)?;
+
+    for share in 0..shares {
+        refresh_context(ctx)?;
+        results.push(create_share(
+            ctx,
+            &mut scratch,
+            polynomial,
+            prime,
+            |chunk| next_x(rng, share, chunk),
+        )?);
+    }
+
+    Ok(results)
+}
+
+/// Creates single share evaluating every chunk polynomial at x-coordinate taken from given source.
+/// Source is called with the chunk index.
+///
+#[inline(always)]
+fn create_share<F>(
+    ctx: &mut BigNumContextRef,
+    scratch: &mut ScratchArena,
+    polynomial: &[Vec<BigNum>],
+    prime: &BigNum,
+    mut next_x: F,
+) -> Result<Vec<u8>, SSSError>
+where
+    F: FnMut(usize) -> Result<BigNum, SSSError>,
+{
+    let mut bytes: Vec<u8> =
+        Vec::with_capacity(SHARE_HEADER_SIZE + polynomial.len() * COEFFICIENTS_SIZE);
+    bytes.push(SHARE_FORMAT_VERSION);
+    for (chunk, coefficients) in polynomial.iter().enumerate() {
+        // Prime above 2^256 leaves points not fitting the share bytes, x is drawn again then.
+        let (coefficient_x, coefficient_y) = loop {
+            let coefficient_x = next_x(chunk)?;
+            if coefficient_x.num_bits() == 0 || coefficient_x >= *prime {
+                return Err(SSSError::WithReason(
+                    "x-coordinate shall be between 1 and prime - 1.".to_owned(),
+                ));
+            }
+
+            let coefficient_y = evaluate_with(ctx, scratch, coefficients, &coefficient_x, prime)?;
+            if fits_share_bytes(&coefficient_x) && fits_share_bytes(&coefficient_y) {
+                break (coefficient_x, coefficient_y);
+            }
+        };
+        let coefficients: &[BigNum; 2] = &[coefficient_x, coefficient_y];
+        bytes.extend(big_nums_to_bytes(coefficients));
+    }
+
+    Ok(bytes)
+}
+
+/// Crates shares from given secret creating shares in parallel.
+/// Each worker uses its own BigNumContext and draws from the thread safe Openssl generator,
+/// used x-coordinates are shared behind a lock to keep them distinct. Shares are collected in order.
+///
+#[cfg(feature = "rayon")]
+#[inline(always)]
+pub(crate) fn create_shares_parallel(
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    use rayon::prelude::*;
+    use std::sync::Mutex;
+
+    let prime = default_prime()?;
+    let mut ctx = BigNumContext::new()?;
+    validate_create_args(&mut ctx, min, shares, prime)?;
+
+    let polynomial = secret_polynomial(&mut OpensslRng, min, secret, prime)?;
+    let used = Mutex::new(vec![HashSet::with_capacity(shares); polynomial.len()]);
+
+    (0..shares)
+        .into_par_iter()
+        .map(|_| {
+            let mut ctx = BigNumContext::new()?;
+            let mut scratch = ScratchArena::new(1)?;
+            create_share(&mut ctx, &mut scratch, &polynomial, prime, |chunk| {
+                let mut used = used.lock().map_err(|_| {
+                    SSSError::WithReason("Used x-coordinates lock is poisoned.".to_owned())
+                })?;
+                distinct_random_x(&mut OpensslRng, &mut used[chunk], prime)
+            })
+        })
+        .collect::<Result<Vec<Vec<u8>>, SSSError>>()
+}
+
+/// Validates custom prime, shall be a prime above 2^256, so every secret chunk is in the field,
+/// and below 2^257, so share points fit the 32 bytes share field elements at least every second
+/// x-coordinate drawn.
+///
+#[inline(always)]
+pub(crate) fn validate_custom_prime(
+    ctx: &mut BigNumContext,
+    prime: &BigNum,
+) -> Result<(), SSSError> {
+    let chunk_bits = U8S_TO_BIG_INT_INITIAL as i32 * 8;
+    if prime.num_bits() != chunk_bits + 1 {
+        return Err(SSSError::WithReason(format!(
+            "Custom prime shall be above 2^{chunk_bits} and below 2^{}.",
+            chunk_bits + 1
+        )));
+    }
+    if !prime.is_prime(64, ctx)? {
+        return Err(SSSError::WithReason(
+            "Custom prime is not a prime number.".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns true when the number fits the share field element of U8S_TO_BIG_INT_INITIAL bytes.
+///
+#[inline(always)]
+fn fits_share_bytes(num: &BigNum) -> bool {
+    num.num_bytes() as usize <= U8S_TO_BIG_INT_INITIAL
+}
+
+/// Validates that shares count is small enough relative to the square root of the prime,
+/// so the probability of two shares drawing the same random x-coordinate stays negligible.
+///
+#[inline(always)]
+fn validate_x_collision_risk(
+    ctx: &mut BigNumContext,
+    shares: usize,
+    prime: &BigNum,
+) -> Result<(), SSSError> {
+    let total = BigNum::from_slice(&shares.to_be_bytes())?;
+    let mut squared = BigNum::new()?;
+    squared.sqr(&total, ctx)?;
+    let mut bound = BigNum::new()?;
+    bound.lshift(&squared, X_COLLISION_SAFETY_BITS)?;
+    if bound >= *prime {
+        return Err(SSSError::WithReason(format!(
+            "Total shares count {shares} is too big for the prime, x-coordinates are likely to collide."
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validates that the prime has more bits than the secret chunk, otherwise chunk values may exceed
+/// the field and be silently reduced. Prime of exactly the chunk bit length, as the legacy prime,
+/// cannot represent the chunk values from the prime up to 2^256 - 1, so it is rejected too.
+/// Shares of the legacy prime are still combined, combining does not validate the prime size.
+///
+#[inline(always)]
+fn validate_prime_size(prime: &BigNum) -> Result<(), SSSError> {
+    if prime.num_bits() as usize <= U8S_TO_BIG_INT_INITIAL * 8 {
+        return Err(SSSError::WithReason(
+            "prime too small for chunk size; secret values may exceed the field".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Recreates secret from given shares.
+/// If number of shares is to small the secret calculated from them will not be correct.
+/// Less then two shares are rejected, as a single share carries its own polynomial points only.
+/// Shares are dispatched by the format version, legacy shares without version byte are accepted.
 /// Function uses Openssl library for cryptographically secure pseudo-random number generation and
 /// BigNum from Openssl big num package to calculate coefficients up to 64 bytes in size.
 ///
 #[inline(always)]
-pub(crate) fn create_shares(
+pub(crate) fn combine_shares(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    interpolate_at(&shares, &BigNum::new()?)
+}
+
+/// Evaluates the chunk polynomials recreated from given shares at given point using Lagrange
+/// interpolation, evaluating at 0 recreates the secret. Every chunk polynomial has its own
+/// x-coordinates, so the value of each chunk is returned as 32 bytes in the order of the chunks.
+/// Point at which a value does not fit 32 bytes is rejected.
+///
+/// # Argument
+///
+/// * `shares`  - shares of the polynomials, equal or more the minimal share count.
+/// * `x`       - point to evaluate the polynomials at.
+///
+/// # Examples
+///
+/// ```
+///use openssl::bn::BigNum;
+///use shamirss::{combine_std, create_std, interpolate_at};
+///
+///let secret = vec![7; 64];
+///let shares = create_std(2, 3, &secret).unwrap();
+///let at_zero = interpolate_at(&shares[1..], &BigNum::new().unwrap()).unwrap();
+///assert_eq!(secret, at_zero);
+///let at_five = interpolate_at(&shares[..2], &BigNum::from_u32(5).unwrap()).unwrap();
+///assert_eq!(at_five, interpolate_at(&shares[1..], &BigNum::from_u32(5).unwrap()).unwrap());
+///```
+///
+pub fn interpolate_at(shares: &[Vec<u8>], x: &BigNum) -> Result<Vec<u8>, SSSError> {
+    let mut ctx = BigNumContext::new()?;
+    validate_shares_provided(shares)?;
+    let prime = shares_prime(shares)?;
+    interpolate_secret(&mut ctx, shares, x, prime)
+}
+
+/// Recreates secret from given shares using given big num context for mod calculations.
+/// Lets the caller reuse a single context when combining shares of many secrets.
+///
+#[inline(always)]
+pub(crate) fn combine_shares_with_context(
+    ctx: &mut BigNumContext,
+    shares: Vec<Vec<u8>>,
+) -> Result<Vec<u8>, SSSError> {
+    validate_shares_provided(&shares)?;
+    let prime = shares_prime(&shares)?;
+    interpolate_secret(ctx, &shares, &BigNum::new()?, prime)
+}
+
+/// Creates shared secrets from given secret with the minimum shares count written to the header.
+/// Shares are of format version 3 and combine as version 2 shares do.
+///
+#[inline(always)]
+pub(crate) fn create_shares_with_header(
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    let header_min = u8::try_from(min).map_err(|_| {
+        SSSError::WithReason(format!(
+            "Minimum value cannot be bigger then {} for shares with header.",
+            u8::MAX
+        ))
+    })?;
+    let mut results = create_shares(min, shares, secret)?;
+    for share in results.iter_mut() {
+        share[0] = THRESHOLD_FORMAT_VERSION;
+        share.insert(1, header_min);
+    }
+
+    Ok(results)
+}
+
+/// Recreates secret from given shares created with create_shares_with_header.
+/// All shares shall carry the same minimum shares count, fewer distinct shares are rejected
+/// instead of recreating a wrong secret.
+///
+#[inline(always)]
+pub(crate) fn combine_shares_checked(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    let mut min = None;
+    for (i, share) in shares.iter().enumerate() {
+        if share_version(share)? != THRESHOLD_FORMAT_VERSION {
+            return Err(SSSError::WithReason(format!(
+                "Share at index {i} has no threshold header."
+            )));
+        }
+        let share_min = share[1] as usize;
+        if *min.get_or_insert(share_min) != share_min {
+            return Err(SSSError::WithReason(format!(
+                "Share at index {i} has minimum shares count {share_min}, other shares differ."
+            )));
+        }
+    }
+    let min = min.unwrap_or(MIN_SHARES_COUNT).max(MIN_SHARES_COUNT);
+    let distinct = shares.iter().collect::<HashSet<_>>().len();
+    if distinct < min {
+        return Err(SSSError::WithReason(format!(
+            "insufficient shares, at least {min} distinct shares are required, got {distinct}."
+        )));
+    }
+
+    combine_shares(shares)
+}
+
+/// Creates indexed shares from given secret, share i is evaluated at x = i + 1 for every chunk.
+/// Share holds the one byte index followed by the y-coordinates only, so it is half the size of
+/// the version 2 share. Chunk polynomials with a point not fitting the share bytes are drawn again.
+///
+#[inline(always)]
+pub(crate) fn create_shares_indexed(
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if shares > u8::MAX as usize {
+        return Err(SSSError::WithReason(format!(
+            "Total shares cannot be bigger then {} for indexed shares.",
+            u8::MAX
+        )));
+    }
+    let mut ctx = BigNumContext::new()?;
+    let prime = default_prime()?;
+    validate_create_args(&mut ctx, min, shares, prime)?;
+
+    let xs = (1..=shares as u32)
+        .map(BigNum::from_u32)
+        .collect::<Result<Vec<BigNum>, _>>()?;
+    let chunks = secret.len().div_ceil(U8S_TO_BIG_INT_INITIAL);
+    let mut results: Vec<Vec<u8>> = (1..=shares as u8)
+        .map(|index| {
+            let mut bytes = Vec::with_capacity(INDEX_SIZE + chunks * U8S_TO_BIG_INT_INITIAL);
+            bytes.push(index);
+            bytes
+        })
+        .collect();
+    let mut scratch = ScratchArena::new(1)?;
+
+    for chunk in 0..chunks {
+        refresh_context(&mut ctx)?;
+        let secret_chunk =
+            &secret[chunk * U8S_TO_BIG_INT_INITIAL..(chunk + 1) * U8S_TO_BIG_INT_INITIAL];
+        // x-coordinates are fixed, the random coefficients are drawn again instead.
+        let ys = loop {
+            let polynomial = secret_polynomial(&mut OpensslRng, min, secret_chunk, prime)?;
+            let ys = xs
+                .iter()
+                .map(|x| evaluate_with(&mut ctx, &mut scratch, &polynomial[0], x, prime))
+                .collect::<Result<Vec<BigNum>, _>>()?;
+            if ys.iter().all(fits_share_bytes) {
+                break ClearOnDrop(ys);
+            }
+        };
+        for (share, y) in results.iter_mut().zip(ys.iter()) {
+            share.extend(big_nums_to_bytes(std::slice::from_ref(y)));
+        }
+    }
+
+    Ok(results)
+}
+
+/// Recreates secret from given shares created with create_shares_indexed.
+/// Shares with the same index and body are combined once, the same index with other body is
+/// rejected.
+///
+#[inline(always)]
+pub(crate) fn combine_shares_indexed(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    validate_shares_provided(&shares)?;
+    let mut shares_polynomials: Vec<Vec<Vec<BigNum>>> = Vec::with_capacity(shares.len());
+    let mut indexes: Vec<(u8, &[u8])> = Vec::with_capacity(shares.len());
+    let size = shares.first().map_or(0, |share| share.len());
+
+    for (i, share) in shares.iter().enumerate() {
+        if share.len() != size {
+            return Err(SSSError::ShareSizeMismatch {
+                expected: size,
+                got: share.len(),
+            });
+        }
+        if share.len() <= INDEX_SIZE || (share.len() - INDEX_SIZE) % U8S_TO_BIG_INT_INITIAL != 0 {
+            return Err(SSSError::ShareNotAligned {
+                len: share.len() - INDEX_SIZE.min(share.len()),
+                block: U8S_TO_BIG_INT_INITIAL,
+            });
+        }
+        let (index, body) = (share[0], &share[INDEX_SIZE..]);
+        if index == 0 {
+            return Err(SSSError::WithReason(format!(
+                "Share at index {i} has share index 0."
+            )));
+        }
+        match indexes.iter().find(|(other, _)| *other == index) {
+            Some((_, other_body)) if *other_body == body => continue,
+            Some(_) => {
+                return Err(SSSError::WithReason(format!(
+                    "Share at index {i} has share index {index} of other share with different body."
+                )))
+            }
+            None => indexes.push((index, body)),
+        }
+        let x = BigNum::from_u32(index as u32)?;
+        let mut polynomials: Vec<Vec<BigNum>> =
+            Vec::with_capacity(body.len() / U8S_TO_BIG_INT_INITIAL);
+        for y in bytes_to_big_nums(body)? {
+            polynomials.push(vec![x.to_owned()?, y]);
+        }
+        shares_polynomials.push(polynomials);
+    }
+
+    if shares_polynomials.len() < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "At least {MIN_SHARES_COUNT} distinct shares are required to combine, got {}.",
+            shares_polynomials.len()
+        )));
+    }
+
+    let mut ctx = BigNumContext::new()?;
+    let pre_secret_coeffisiances = ClearOnDrop(interpolate_chunks(
+        &mut ctx,
+        &shares_polynomials,
+        &BigNum::new()?,
+        default_prime()?,
+    )?);
+
+    chunks_to_bytes(&pre_secret_coeffisiances)
+}
+
+/// Recreates secret from given shares created with given custom prime.
+/// Prime is not stored in the shares, shares combined with other prime give a wrong secret.
+///
+#[inline(always)]
+pub(crate) fn combine_shares_with_prime(
+    shares: Vec<Vec<u8>>,
+    prime: &BigNum,
+) -> Result<Vec<u8>, SSSError> {
+    validate_shares_provided(&shares)?;
+    let mut ctx = BigNumContext::new()?;
+    validate_custom_prime(&mut ctx, prime)?;
+    interpolate_secret(&mut ctx, &shares, &BigNum::new()?, prime)
+}
+
+/// Crates shares from given secret split to blocks of the configured size, calculated over the
+/// configured prime. Share consists of the x and y coordinates of every block, each of the prime
+/// size in bytes, without the format version byte.
+///
+#[inline(always)]
+pub(crate) fn create_shares_with_config(
+    min: usize,
+    shares: usize,
+    secret: &[u8],
+    config: &SplitConfig,
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if min > shares {
+        return Err(SSSError::MinGreaterThanShares { min, shares });
+    }
+    if min < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "Minimum value cannot be smaller then {MIN_SHARES_COUNT}."
+        )));
+    }
+    let mut ctx = BigNumContext::new()?;
+    let prime = config.prime();
+    validate_x_collision_risk(&mut ctx, shares, prime)?;
+
+    let size = config.element_size() as i32;
+    let mut results: Vec<Vec<u8>> = (0..shares)
+        .map(|_| Vec::with_capacity(secret.len() / config.block() * 2 * size as usize))
+        .collect();
+    let mut scratch = ScratchArena::new(1)?;
+    for block in secret.chunks(config.block()) {
+        refresh_context(&mut ctx)?;
+        let mut coefficients = ClearOnDrop(Vec::with_capacity(min));
+        coefficients.push(BigNum::from_slice(block)?);
+        for _ in 1..min {
+            coefficients.push(OpensslRng.gen_below(prime)?);
+        }
+        let mut used = HashSet::with_capacity(shares);
+        for share in results.iter_mut() {
+            let coefficient_x = distinct_random_x(&mut OpensslRng, &mut used, prime)?;
+            let coefficient_y =
+                evaluate_with(&mut ctx, &mut scratch, &coefficients, &coefficient_x, prime)?;
+            share.extend(coefficient_x.to_vec_padded(size)?);
+            share.extend(coefficient_y.to_vec_padded(size)?);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Recreates secret from given shares created with create_shares_with_config and the same config.
+///
+#[inline(always)]
+pub(crate) fn combine_shares_with_config(
+    shares: Vec<Vec<u8>>,
+    config: &SplitConfig,
+) -> Result<Vec<u8>, SSSError> {
+    validate_shares_provided(&shares)?;
+    let size = config.element_size();
+    let unit_size = 2 * size;
+    let mut bodies: Vec<&[u8]> = Vec::with_capacity(shares.len());
+    let mut shares_polynomials: Vec<Vec<Vec<BigNum>>> = Vec::with_capacity(shares.len());
+    for share in shares.iter() {
+        if share.is_empty() || share.len() % unit_size != 0 {
+            return Err(SSSError::ShareNotAligned {
+                len: share.len(),
+                block: unit_size,
+            });
+        }
+        if share.len() != shares[0].len() {
+            return Err(SSSError::ShareSizeMismatch {
+                expected: shares[0].len(),
+                got: share.len(),
+            });
+        }
+        if bodies.contains(&share.as_slice()) {
+            continue;
+        }
+        bodies.push(share);
+        let mut polynomials: Vec<Vec<BigNum>> = Vec::with_capacity(share.len() / unit_size);
+        for unit in share.chunks(unit_size) {
+            polynomials.push(vec![
+                BigNum::from_slice(&unit[..size])?,
+                BigNum::from_slice(&unit[size..])?,
+            ]);
+        }
+        shares_polynomials.push(polynomials);
+    }
+    if shares_polynomials.len() < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "At least {MIN_SHARES_COUNT} distinct shares are required to combine, got {}.",
+            shares_polynomials.len()
+        )));
+    }
+
+    let mut ctx = BigNumContext::new()?;
+    let blocks = ClearOnDrop(interpolate_chunks(
+        &mut ctx,
+        &shares_polynomials,
+        &BigNum::new()?,
+        config.prime(),
+    )?);
+    let mut secret = Vec::with_capacity(blocks.len() * config.block());
+    for block in blocks.iter() {
+        if block.num_bytes() as usize > config.block() {
+            wipe_bytes(&mut secret);
+            return Err(SSSError::WithReason(format!(
+                "Recreated block does not fit {} bytes, shares do not match the config.",
+                config.block()
+            )));
+        }
+        secret.extend(block.to_vec_padded(config.block() as i32)?);
+    }
+
+    Ok(secret)
+}
+
+/// Validates any share bytes are given, empty input is a caller error rather than a wrong share.
+///
+#[inline(always)]
+pub(crate) fn validate_shares_provided(shares: &[Vec<u8>]) -> Result<(), SSSError> {
+    if shares.iter().all(|share| share.is_empty()) {
+        return Err(SSSError::WithReason("no shares provided".to_owned()));
+    }
+
+    Ok(())
+}
+
+/// Interpolates chunks of given shares at given point using given prime.
+///
+#[inline(always)]
+fn interpolate_secret(
+    ctx: &mut BigNumContext,
+    shares: &[Vec<u8>],
+    at: &BigNum,
+    prime: &BigNum,
+) -> Result<Vec<u8>, SSSError> {
+    if shares.len() < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "At least {MIN_SHARES_COUNT} shares are required to combine, got {}.",
+            shares.len()
+        )));
+    }
+
+    let shares_polynomials = shares_to_polynomials(shares)?;
+    let pre_secret_coeffisiances =
+        ClearOnDrop(interpolate_chunks(ctx, &shares_polynomials, at, prime)?);
+
+    chunks_to_bytes(&pre_secret_coeffisiances)
+}
+
+/// Maps interpolated chunks to bytes. Inconsistent or forged shares may interpolate to any value
+/// of the field, chunk not fitting U8S_TO_BIG_INT_INITIAL bytes is rejected instead of changing
+/// the secret size.
+///
+#[inline(always)]
+fn chunks_to_bytes(chunks: &[BigNum]) -> Result<Vec<u8>, SSSError> {
+    if !chunks.iter().all(fits_share_bytes) {
+        return Err(SSSError::WithReason(format!(
+            "Interpolated value does not fit {U8S_TO_BIG_INT_INITIAL} bytes."
+        )));
+    }
+
+    Ok(big_nums_to_bytes(chunks))
+}
+
+/// Recreates the secret prefix covered by all given shares.
+/// Shares may hold different chunks count, only the chunks common to all shares are combined,
+/// which lets a custodian keep only a prefix of the share chunks.
+///
+#[inline(always)]
+pub(crate) fn combine_shares_prefix(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    let bodies = shares
+        .iter()
+        .map(|share| share_body(share))
+        .collect::<Result<Vec<&[u8]>, SSSError>>()?;
+    let common = bodies.iter().map(|body| body.len()).min().unwrap_or(0);
+
+    combine_shares(
+        shares
+            .iter()
+            .zip(bodies.iter())
+            .map(|(share, body)| share[..share.len() - body.len() + common].to_vec())
+            .collect(),
+    )
+}
+
+/// Recreates secret from the first min shares and verifies that every other given share lies on
+/// the recreated chunk polynomials. Secret is returned only when all held-out shares are
+/// consistent, so at least min + 1 shares are required.
+///
+#[inline(always)]
+pub(crate) fn combine_shares_verified(
+    shares: Vec<Vec<u8>>,
+    min: usize,
+) -> Result<Vec<u8>, SSSError> {
+    if min < MIN_SHARES_COUNT || shares.len() <= min {
+        return Err(SSSError::WithReason(format!(
+            "At least {} shares are required to combine and verify, got {}.",
+            min.max(MIN_SHARES_COUNT) + 1,
+            shares.len()
+        )));
+    }
+
+    let mut ctx = BigNumContext::new()?;
+    let prime = shares_prime(&shares)?;
+
+    let shares_polynomials = shares_to_polynomials(&shares)?;
+    if shares_polynomials.len() <= min {
+        return Err(SSSError::WithReason(format!(
+            "At least {} distinct shares are required to combine and verify, got {}.",
+            min + 1,
+            shares_polynomials.len()
+        )));
+    }
+    let (used, held_out) = shares_polynomials.split_at(min);
+    let share_count = used.first().map_or(0, |p| p.len());
+    let mut pre_secret_coeffisiances: ClearOnDrop<Vec<BigNum>> =
+        ClearOnDrop(Vec::with_capacity(share_count));
+
+    for j in 0..share_count {
+        refresh_context(&mut ctx)?;
+        pre_secret_coeffisiances.push(interpolate_chunk(&mut ctx, used, j, prime)?);
+        for (k, polys_k) in held_out.iter().enumerate() {
+            let expected = interpolate_chunk_at(&mut ctx, used, j, &polys_k[j][0], prime)?;
+            if expected != polys_k[j][1] {
+                return Err(SSSError::WithReason(format!(
+                    "Share at index {} is not consistent with the first {min} shares.",
+                    min + k
+                )));
+            }
+        }
+    }
+
+    chunks_to_bytes(&pre_secret_coeffisiances)
+}
+
+/// Repairs the damaged share from the first min given shares.
+/// Header and x-coordinates of the damaged share are kept and the y-coordinates are re-derived
+/// by interpolating the chunk polynomials at them, so the original share is restored.
+///
+#[inline(always)]
+pub(crate) fn repair_share(
+    shares: Vec<Vec<u8>>,
+    damaged: &[u8],
+    min: usize,
+) -> Result<Vec<u8>, SSSError> {
+    if min < MIN_SHARES_COUNT || shares.len() < min {
+        return Err(SSSError::WithReason(format!(
+            "At least {} shares are required to repair a share, got {}.",
+            min.max(MIN_SHARES_COUNT),
+            shares.len()
+        )));
+    }
+
+    let mut ctx = BigNumContext::new()?;
+    let prime = shares_prime(&shares)?;
+
+    let shares_polynomials = shares_to_polynomials(&shares)?;
+    if shares_polynomials.len() < min {
+        return Err(SSSError::WithReason(format!(
+            "At least {min} distinct shares are required to repair a share, got {}.",
+            shares_polynomials.len()
+        )));
+    }
+    let used = &shares_polynomials[..min];
+    let share_count = used.first().map_or(0, |p| p.len());
+    let damaged_body = share_body(damaged)?;
+    if damaged_body.len() != share_count * COEFFICIENTS_SIZE {
+        return Err(SSSError::ShareSizeMismatch {
+            expected: share_count * COEFFICIENTS_SIZE,
+            got: damaged_body.len(),
+        });
+    }
+    let mut bytes: Vec<u8> = damaged[..damaged.len() - damaged_body.len()].to_vec();
+    bytes.reserve(share_count * COEFFICIENTS_SIZE);
+
+    for (j, unit) in damaged_body.chunks(COEFFICIENTS_SIZE).enumerate() {
+        refresh_context(&mut ctx)?;
+        let coefficient_x = BigNum::from_slice(&unit[..U8S_TO_BIG_INT_INITIAL])?;
+        if coefficient_x.num_bits() == 0 || coefficient_x >= *prime {
+            return Err(SSSError::WithReason(format!(
+                "x-coordinate of the damaged share chunk {j} is out of the field."
+            )));
+        }
+        let coefficient_y = interpolate_chunk_at(&mut ctx, used, j, &coefficient_x, prime)?;
+        if !fits_share_bytes(&coefficient_y) {
+            return Err(SSSError::WithReason(format!(
+                "Repaired share chunk {j} does not fit the share, x-coordinate is corrupted."
+            )));
+        }
+        bytes.extend(big_nums_to_bytes(&[coefficient_x, coefficient_y]));
+    }
+
+    Ok(bytes)
+}
+
+/// Creates a fresh set of shares of the secret of given shares, as many shares as given.
+/// Chunk polynomials are interpolated from the first min shares and a random polynomial with
+/// zero constant term is added to each of them, so the secret stays the same, but new shares
+/// lie on other polynomials and do not combine with the old shares. New x-coordinates are
+/// distinct from the old ones.
+///
+#[inline(always)]
+pub(crate) fn refresh_shares(shares: Vec<Vec<u8>>, min: usize) -> Result<Vec<Vec<u8>>, SSSError> {
+    if min < MIN_SHARES_COUNT || shares.len() < min {
+        return Err(SSSError::WithReason(format!(
+            "At least {} shares are required to refresh shares, got {}.",
+            min.max(MIN_SHARES_COUNT),
+            shares.len()
+        )));
+    }
+
+    let mut ctx = BigNumContext::new()?;
+    let prime = shares_prime(&shares)?;
+    validate_x_collision_risk(&mut ctx, 2 * shares.len(), prime)?;
+
+    let shares_polynomials = shares_to_polynomials(&shares)?;
+    if shares_polynomials.len() < min {
+        return Err(SSSError::WithReason(format!(
+            "At least {min} distinct shares are required to refresh shares, got {}.",
+            shares_polynomials.len()
+        )));
+    }
+    let used = &shares_polynomials[..min];
+    let chunks = used.first().map_or(0, |p| p.len());
+    let header = derived_share_header(&shares[0])?;
+
+    let mut zero_polynomial: ClearOnDrop<Vec<Vec<BigNum>>> =
+        ClearOnDrop(Vec::with_capacity(chunks));
+    let mut used_x: Vec<HashSet<Vec<u8>>> = Vec::with_capacity(chunks);
+    for j in 0..chunks {
+        zero_polynomial.push(Vec::with_capacity(min));
+        zero_polynomial[j].push(BigNum::new()?);
+        for _ in 1..min {
+            zero_polynomial[j].push(OpensslRng.gen_below(prime)?);
+        }
+        used_x.push(
+            shares_polynomials
+                .iter()
+                .map(|p| p[j][0].to_vec())
+                .collect(),
+        );
+    }
+
+    let mut results: Vec<Vec<u8>> = Vec::with_capacity(shares.len());
+    let mut scratch = ScratchArena::new(1)?;
+    for _ in 0..shares.len() {
+        let mut bytes = header.clone();
+        bytes.reserve(chunks * COEFFICIENTS_SIZE);
+        for j in 0..chunks {
+            refresh_context(&mut ctx)?;
+            let (coefficient_x, coefficient_y) = loop {
+                let coefficient_x = distinct_random_x(&mut OpensslRng, &mut used_x[j], prime)?;
+                if !fits_share_bytes(&coefficient_x) {
+                    continue;
+                }
+                let old_y = ClearOnDrop(interpolate_chunk_at(
+                    &mut ctx,
+                    used,
+                    j,
+                    &coefficient_x,
+                    prime,
+                )?);
+                let delta = ClearOnDrop(evaluate_with(
+                    &mut ctx,
+                    &mut scratch,
+                    &zero_polynomial[j],
+                    &coefficient_x,
+                    prime,
+                )?);
+                let mut coefficient_y = BigNum::new()?;
+                coefficient_y.mod_add(&old_y, &delta, prime, &mut ctx)?;
+                if fits_share_bytes(&coefficient_y) {
+                    break (coefficient_x, coefficient_y);
+                }
+            };
+            bytes.extend(big_nums_to_bytes(&[coefficient_x, coefficient_y]));
+        }
+        results.push(bytes);
+    }
+
+    Ok(results)
+}
+
+/// Returns header for a share derived from given share, legacy shares get version 1 header.
+///
+#[inline(always)]
+fn derived_share_header(share: &[u8]) -> Result<Vec<u8>, SSSError> {
+    let header = &share[..share.len() - share_body(share)?.len()];
+    if header.is_empty() {
+        return Ok(vec![LEGACY_PRIME_FORMAT_VERSION]);
+    }
+    Ok(header.to_vec())
+}
+
+/// Recreates secret from every min-sized subset of given shares and returns it only when all
+/// subsets agree. Subsets disagreeing with the most common secret are listed by share indexes.
+/// Number of subsets C(n, min) is checked against max_subsets before any combine is done.
+/// Deadline is checked before every subset combine.
+///
+#[inline(always)]
+pub(crate) fn combine_all_subsets(
+    shares: &[Vec<u8>],
+    min: usize,
+    max_subsets: usize,
+    deadline: Option<Instant>,
+) -> Result<Vec<u8>, SSSError> {
+    if min < MIN_SHARES_COUNT || min > shares.len() {
+        return Err(SSSError::WithReason(format!(
+            "Subset size {min} has to be between {MIN_SHARES_COUNT} and shares count {}.",
+            shares.len()
+        )));
+    }
+
+    let subsets_count = subsets_count(shares.len(), min);
+    if subsets_count.map_or(true, |count| count > max_subsets) {
+        return Err(SSSError::WithReason(format!(
+            "Combining {} shares in subsets of {min} exceeds the limit of {max_subsets} subsets.",
+            shares.len()
+        )));
+    }
+
+    let mut subset: Vec<usize> = (0..min).collect();
+    // Distinct recreated secrets, each subset refers to the secret it recreated, if any.
+    // Secret equal to an already recreated one is wiped as soon as it is compared.
+    let mut secrets: Vec<Vec<u8>> = Vec::new();
+    let mut results: Vec<(Vec<usize>, Option<usize>)> = Vec::new();
+    loop {
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            secrets.iter_mut().for_each(|secret| wipe_bytes(secret));
+            return Err(SSSError::WithReason(
+                "reconstruction cancelled/timed out".to_owned(),
+            ));
+        }
+        let picked = subset.iter().map(|i| shares[*i].clone()).collect();
+        let recreated = match combine_shares(picked) {
+            Ok(mut secret) => match secrets.iter().position(|other| *other == secret) {
+                Some(position) => {
+                    wipe_bytes(&mut secret);
+                    Some(position)
+                }
+                None => {
+                    secrets.push(secret);
+                    Some(secrets.len() - 1)
+                }
+            },
+            Err(_) => None,
+        };
+        results.push((subset.clone(), recreated));
+
+        let Some(pos) = (0..min).rev().find(|&p| subset[p] < shares.len() - min + p) else {
+            break;
+        };
+        subset[pos] += 1;
+        for p in pos + 1..min {
+            subset[p] = subset[p - 1] + 1;
+        }
+    }
+
+    let mut counts = vec![0; secrets.len()];
+    for (_, recreated) in results.iter() {
+        if let Some(position) = recreated {
+            counts[*position] += 1;
+        }
+    }
+    let mut majority: Option<usize> = None;
+    let mut majority_count = 0;
+    for (position, count) in counts.iter().enumerate() {
+        if *count > majority_count {
+            majority = Some(position);
+            majority_count = *count;
+        }
+    }
+
+    let disagreeing: Vec<&Vec<usize>> = results
+        .iter()
+        .filter(|(_, recreated)| *recreated != majority)
+        .map(|(subset, _)| subset)
+        .collect();
+    let secret = match majority {
+        Some(position) if disagreeing.is_empty() => Some(secrets.swap_remove(position)),
+        _ => None,
+    };
+    secrets.iter_mut().for_each(|secret| wipe_bytes(secret));
+    secret.ok_or_else(|| {
+        SSSError::WithReason(format!(
+            "Subsets of shares do not agree on the secret, disagreeing subsets: {disagreeing:?}."
+        ))
+    })
+}
+
+/// Calculates binomial coefficient C(n, k), None on overflow.
+///
+#[inline(always)]
+fn subsets_count(n: usize, k: usize) -> Option<usize> {
+    let k = k.min(n - k);
+    let mut result: usize = 1;
+    for i in 0..k {
+        result = result.checked_mul(n - i)? / (i + 1);
+    }
+    Some(result)
+}
+
+/// Crates multi-secret shares from given secrets of equal size sharing x-coordinates.
+/// Share starts with the SHARE_FORMAT_VERSION byte and the secrets count byte followed by one unit
+/// per chunk, unit is the chunk x-coordinate followed by y-coordinate of every secret, each
+/// U8S_TO_BIG_INT_INITIAL in size.
+///
+#[inline(always)]
+pub(crate) fn create_shares_multisecret(
     min: usize,
     shares: usize,
-    secret: &[u8],
+    secrets: &[&[u8]],
 ) -> Result<Vec<Vec<u8>>, SSSError> {
-    if min > shares {
+    let secrets_count = validate_multisecret_count(secrets.len())?;
+    create_multisecret_units(
+        min,
+        shares,
+        secrets,
+        default_prime()?,
+        &[SHARE_FORMAT_VERSION, secrets_count],
+    )
+}
+
+/// Crates multi-secret shares over given prime, every share starts with given header.
+/// Prime above 2^256 leaves points not fitting the share bytes, x is drawn again then.
+///
+#[inline(always)]
+fn create_multisecret_units(
+    min: usize,
+    shares: usize,
+    secrets: &[&[u8]],
+    prime: &BigNum,
+    header: &[u8],
+) -> Result<Vec<Vec<u8>>, SSSError> {
+    if secrets
+        .iter()
+        .any(|secret| secret.len() != secrets[0].len())
+    {
         return Err(SSSError::WithReason(
-            "Minimum value cannot be bigger then total shares.".to_owned(),
+            "All secrets shall have the same size.".to_owned(),
         ));
     }
+    if min > shares {
+        return Err(SSSError::MinGreaterThanShares { min, shares });
+    }
+    if min < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "Minimum value cannot be smaller then {MIN_SHARES_COUNT}."
+        )));
+    }
 
     let mut ctx = BigNumContext::new()?;
-    let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
-
-    let secret = bytes_to_big_nums(secret)?;
-    let mut polynomial: Vec<Vec<BigNum>> = Vec::with_capacity(secret.len());
-    for part in secret.iter() {
-        let mut coefficients = Vec::with_capacity(min - 1);
-        let temp = BigNum::from_slice(&part.to_vec())?;
-        coefficients.push(temp);
-        for _ in 1..min {
-            coefficients.push(random(&prime)?);
-        }
-        polynomial.push(coefficients);
+    validate_x_collision_risk(&mut ctx, shares, prime)?;
+
+    let mut polynomials: Vec<ClearOnDrop<Vec<Vec<BigNum>>>> = Vec::with_capacity(secrets.len());
+    for secret in secrets.iter() {
+        polynomials.push(secret_polynomial(&mut OpensslRng, min, secret, prime)?);
     }
+    let chunks = polynomials[0].len();
 
     let mut results: Vec<Vec<u8>> = Vec::with_capacity(shares);
-
+    let mut scratch = ScratchArena::new(1)?;
+    let mut used: Vec<HashSet<Vec<u8>>> = vec![HashSet::with_capacity(shares); chunks];
     for _ in 0..shares {
-        let mut bytes: Vec<u8> = Vec::with_capacity(secret.len() * COEFFICIENTS_SIZE);
-        let mut counter = 0;
-        while counter < secret.len() {
-            let coefficient_x = random(&prime)?;
-
-            let coefficient_y = evaluate(&mut ctx, &polynomial[counter], &coefficient_x, &prime)?;
-            let coefficients: &[BigNum; 2] = &[coefficient_x, coefficient_y];
-            bytes.extend(big_nums_to_bytes(coefficients));
-            counter += 1;
+        refresh_context(&mut ctx)?;
+        let mut bytes: Vec<u8> = Vec::with_capacity(
+            header.len() + chunks * (1 + secrets.len()) * U8S_TO_BIG_INT_INITIAL,
+        );
+        bytes.extend(header);
+        for j in 0..chunks {
+            let unit = loop {
+                let coefficient_x = distinct_random_x(&mut OpensslRng, &mut used[j], prime)?;
+                let mut unit = Vec::with_capacity(1 + secrets.len());
+                for polynomial in polynomials.iter() {
+                    unit.push(evaluate_with(
+                        &mut ctx,
+                        &mut scratch,
+                        &polynomial[j],
+                        &coefficient_x,
+                        prime,
+                    )?);
+                }
+                unit.insert(0, coefficient_x);
+                if unit.iter().all(fits_share_bytes) {
+                    break unit;
+                }
+            };
+            bytes.extend(big_nums_to_bytes(&unit));
         }
         results.push(bytes);
     }
@@ -59,33 +1305,177 @@ pub(crate) fn create_shares(
     Ok(results)
 }
 
-/// Recreates secret from given shares.
-/// If number of shares is to small the secret calculated from them will not be correct.
-/// Function uses Openssl library for cryptographically secure pseudo-random number generation and
-/// BigNum from Openssl big num package to calculate coefficients up to 64 bytes in size.
+/// Recreates secrets from given multi-secret shares, secrets are returned in creation order.
+/// Legacy multi-secret shares without the format version byte are combined over the legacy prime.
 ///
 #[inline(always)]
-pub(crate) fn combine_shares(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+pub(crate) fn combine_shares_multisecret(shares: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, SSSError> {
+    if shares.len() < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "At least {MIN_SHARES_COUNT} shares are required to combine, got {}.",
+            shares.len()
+        )));
+    }
+    let (header_size, prime) = multisecret_format(&shares[0])?;
+    let header = &shares[0][..header_size];
+    let secrets_count = validate_multisecret_count(header[header_size - 1] as usize)? as usize;
+    let unit_size = (1 + secrets_count) * U8S_TO_BIG_INT_INITIAL;
+    for share in shares.iter() {
+        if share.len() != shares[0].len()
+            || share[..header_size] != *header
+            || (share.len() - header_size) % unit_size != 0
+        {
+            return Err(SSSError::WithReason(format!(
+                "All shares shall hold {secrets_count} secrets and have the same size of {}.",
+                shares[0].len()
+            )));
+        }
+    }
+    let chunks = (shares[0].len() - header_size) / unit_size;
+
     let mut ctx = BigNumContext::new()?;
-    let negative_one = BigNum::from_dec_str("-1")?;
-    let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+    let zero = BigNum::new()?;
+    let mut secrets: Vec<Vec<u8>> = Vec::with_capacity(secrets_count);
+    for secret in 0..secrets_count {
+        let mut shares_polynomials: Vec<Vec<Vec<BigNum>>> = Vec::with_capacity(shares.len());
+        for share in shares.iter() {
+            let mut polynomials: Vec<Vec<BigNum>> = Vec::with_capacity(chunks);
+            for unit in share[header_size..].chunks(unit_size) {
+                let y = (1 + secret) * U8S_TO_BIG_INT_INITIAL;
+                polynomials.push(vec![
+                    BigNum::from_slice(&unit[..U8S_TO_BIG_INT_INITIAL])?,
+                    BigNum::from_slice(&unit[y..y + U8S_TO_BIG_INT_INITIAL])?,
+                ]);
+            }
+            shares_polynomials.push(polynomials);
+        }
+
+        let pre_secret_coeffisiances = ClearOnDrop(interpolate_chunks(
+            &mut ctx,
+            &shares_polynomials,
+            &zero,
+            prime,
+        )?);
+        match chunks_to_bytes(&pre_secret_coeffisiances) {
+            Ok(bytes) => secrets.push(bytes),
+            Err(e) => {
+                secrets.iter_mut().for_each(|secret| wipe_bytes(secret));
+                return Err(e);
+            }
+        }
+    }
+
+    Ok(secrets)
+}
+
+/// Returns header size and prime of given multi-secret share.
+///
+#[inline(always)]
+fn multisecret_format(share: &[u8]) -> Result<(usize, &'static BigNum), SSSError> {
+    match share.len() % U8S_TO_BIG_INT_INITIAL {
+        LEGACY_MULTISECRET_HEADER_SIZE => Ok((LEGACY_MULTISECRET_HEADER_SIZE, legacy_prime()?)),
+        MULTISECRET_HEADER_SIZE if share[0] == SHARE_FORMAT_VERSION => {
+            Ok((MULTISECRET_HEADER_SIZE, default_prime()?))
+        }
+        _ => Err(SSSError::WithReason(
+            "Share is not a multi-secret share of a supported format.".to_owned(),
+        )),
+    }
+}
+
+/// Builds one polynomial per secret chunk, the chunk is the constant term and the remaining
+/// min - 1 coefficients are random. Polynomials are cleared when dropped, also on error.
+///
+#[inline(always)]
+fn secret_polynomial<R: ShareRng>(
+    rng: &mut R,
+    min: usize,
+    secret: &[u8],
+    prime: &BigNum,
+) -> Result<ClearOnDrop<Vec<Vec<BigNum>>>, SSSError> {
+    let parts = ClearOnDrop(bytes_to_big_nums(secret)?);
+    let mut polynomial = ClearOnDrop(Vec::with_capacity(parts.len()));
+    for (j, part) in parts.iter().enumerate() {
+        polynomial.push(Vec::with_capacity(min));
+        polynomial[j].push((*part).to_owned()?);
+        for _ in 1..min {
+            polynomial[j].push(rng.gen_below(prime)?);
+        }
+    }
+
+    Ok(polynomial)
+}
+
+/// Validates secrets count fits the multi-secret share header byte.
+///
+#[inline(always)]
+fn validate_multisecret_count(count: usize) -> Result<u8, SSSError> {
+    match u8::try_from(count) {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(SSSError::WithReason(format!(
+            "Secrets count shall be between 1 and {}, got {count}.",
+            u8::MAX
+        ))),
+    }
+}
+
+/// Recreates secret from given shares interpolating chunks in parallel.
+/// Each worker uses its own BigNumContext, chunks are collected in order.
+///
+#[cfg(feature = "rayon")]
+#[inline(always)]
+pub(crate) fn combine_shares_parallel(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError> {
+    use rayon::prelude::*;
+
+    if shares.len() < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "At least {MIN_SHARES_COUNT} shares are required to combine, got {}.",
+            shares.len()
+        )));
+    }
+
+    let prime = shares_prime(&shares)?;
 
+    let shares_polynomials = shares_to_polynomials(&shares)?;
+    let share_count = shares_polynomials.first().map_or(0, |p| p.len());
+    let pre_secret_coeffisiances = ClearOnDrop(
+        (0..share_count)
+            .into_par_iter()
+            .map(|j| {
+                let mut ctx = BigNumContext::new()?;
+                interpolate_chunk(&mut ctx, &shares_polynomials, j, prime)
+            })
+            .collect::<Result<Vec<BigNum>, SSSError>>()?,
+    );
+
+    chunks_to_bytes(&pre_secret_coeffisiances)
+}
+
+/// Parses shares to polynomials points, one (x, y) pair per chunk.
+/// All shares shall have the same chunks count. Exact duplicates of a share body are skipped,
+/// so a share submitted twice does not make the denominator zero.
+///
+#[inline(always)]
+fn shares_to_polynomials(shares: &[Vec<u8>]) -> Result<Vec<Vec<Vec<BigNum>>>, SSSError> {
     let mut shares_polynomials: Vec<Vec<Vec<BigNum>>> = Vec::with_capacity(shares.len());
 
     let mut first_share_count = None;
 
+    let mut bodies: Vec<&[u8]> = Vec::with_capacity(shares.len());
+
     for share in shares.iter() {
-        if share.len() % COEFFICIENTS_SIZE != 0 {
-            return Err(SSSError::WithReason(format!(
-                "Share size is not divisible by {COEFFICIENTS_SIZE}"
-            )));
+        let share = share_body(share)?;
+        if bodies.contains(&share) {
+            continue;
         }
+        bodies.push(share);
         let share_count = share.len() / COEFFICIENTS_SIZE;
         if let Some(first_share_count) = first_share_count {
             if share_count != first_share_count {
-                return Err(SSSError::WithReason(format!(
-                    "All shares shall have the same size of {first_share_count}"
-                )));
+                return Err(SSSError::ShareSizeMismatch {
+                    expected: first_share_count * COEFFICIENTS_SIZE,
+                    got: share.len(),
+                });
             }
         } else {
             first_share_count = Some(share_count);
@@ -99,77 +1489,259 @@ pub(crate) fn combine_shares(shares: Vec<Vec<u8>>) -> Result<Vec<u8>, SSSError>
         shares_polynomials.push(polynomials);
     }
 
-    let share_count = first_share_count.unwrap_or_default();
-    let mut pre_secret_coeffisiances: Vec<BigNum> = Vec::with_capacity(share_count);
+    if shares_polynomials.len() < MIN_SHARES_COUNT {
+        return Err(SSSError::WithReason(format!(
+            "At least {MIN_SHARES_COUNT} distinct shares are required to combine, got {}.",
+            shares_polynomials.len()
+        )));
+    }
 
-    for j in 0..share_count {
-        let mut candidate = BigNum::from_dec_str("0")?;
+    Ok(shares_polynomials)
+}
 
-        for (i, polys_i) in shares_polynomials.iter().enumerate() {
-            let origin = &polys_i[j][0];
-            let origin_y = &polys_i[j][1];
-            let mut numerator = BigNum::from_dec_str("1")?;
-            let mut denominator = BigNum::from_dec_str("1")?;
+/// Interpolates the constant term of the j-th chunk polynomial using Lagrange interpolation.
+///
+#[inline(always)]
+fn interpolate_chunk(
+    ctx: &mut BigNumContextRef,
+    shares_polynomials: &[Vec<Vec<BigNum>>],
+    j: usize,
+    prime: &BigNum,
+) -> Result<BigNum, SSSError> {
+    interpolate_chunk_at(ctx, shares_polynomials, j, &BigNum::new()?, prime)
+}
 
-            'k_iter: for (k, polys_k) in shares_polynomials.iter().enumerate() {
-                if k == i {
-                    continue 'k_iter;
-                }
+/// Evaluates the j-th chunk polynomial at given point using Lagrange interpolation.
+///
+#[inline(always)]
+fn interpolate_chunk_at(
+    ctx: &mut BigNumContextRef,
+    shares_polynomials: &[Vec<Vec<BigNum>>],
+    j: usize,
+    at: &BigNum,
+    prime: &BigNum,
+) -> Result<BigNum, SSSError> {
+    let mut scratch = ScratchArena::new(LAGRANGE_SCRATCH)?;
+    let fraction = ClearOnDrop(lagrange_fraction(
+        ctx,
+        &mut scratch,
+        shares_polynomials,
+        j,
+        at,
+        prime,
+    )?);
+    let (numerator, denominator) = &*fraction;
+    let mut inverse = BigNum::new()?;
+    inverse
+        .mod_inverse(denominator, prime, ctx)
+        .map_err(|_| SSSError::NonInvertible)?;
+    fraction_value(ctx, numerator, &inverse, prime)
+}
 
-                let current = &polys_k[j][0];
-                let mut negative = BigNum::from_dec_str("0")?;
-                negative.checked_mul(&negative_one, current, &mut ctx)?;
+/// Interpolates all chunk polynomials at given point, at 0 the constant terms.
+/// Denominators of all chunks are inverted together with Montgomery batch inversion, so only one
+/// modular inverse is calculated for the whole secret.
+///
+#[inline(always)]
+fn interpolate_chunks(
+    ctx: &mut BigNumContext,
+    shares_polynomials: &[Vec<Vec<BigNum>>],
+    at: &BigNum,
+    prime: &BigNum,
+) -> Result<Vec<BigNum>, SSSError> {
+    let chunks = shares_polynomials.first().map_or(0, |p| p.len());
+    if chunks == 0 {
+        return Ok(Vec::new());
+    }
+    let mut scratch = ScratchArena::new(LAGRANGE_SCRATCH)?;
 
-                let mut added = BigNum::from_dec_str("0")?;
-                added.checked_sub(origin, current)?;
+    let mut fractions: ClearOnDrop<Vec<(BigNum, BigNum)>> = ClearOnDrop(Vec::with_capacity(chunks));
+    let mut prefixes: Vec<BigNum> = Vec::with_capacity(chunks);
+    for j in 0..chunks {
+        refresh_context(ctx)?;
+        let fraction = lagrange_fraction(ctx, &mut scratch, shares_polynomials, j, at, prime)?;
+        let mut prefix = BigNum::new()?;
+        match prefixes.last() {
+            Some(previous) => prefix.mod_mul(previous, &fraction.1, prime, ctx)?,
+            None => prefix = fraction.1.to_owned()?,
+        }
+        prefixes.push(prefix);
+        fractions.push(fraction);
+    }
 
-                let mut temp = BigNum::new()?;
-                temp.checked_mul(&numerator, &negative, &mut ctx)?;
-                numerator.nnmod(&temp, &prime, &mut ctx)?;
+    // inverse holds the inverse of the product of denominators of chunks 0..=j
+    let mut inverse = BigNum::new()?;
+    inverse
+        .mod_inverse(&prefixes[chunks - 1], prime, ctx)
+        .map_err(|_| SSSError::NonInvertible)?;
+    let mut denominator_inverse = BigNum::new()?;
+    let mut temp = BigNum::new()?;
+    let mut results: ClearOnDrop<Vec<BigNum>> = ClearOnDrop(Vec::with_capacity(chunks));
+    for j in (1..chunks).rev() {
+        denominator_inverse.mod_mul(&inverse, &prefixes[j - 1], prime, ctx)?;
+        results.push(fraction_value(
+            ctx,
+            &fractions[j].0,
+            &denominator_inverse,
+            prime,
+        )?);
+        temp.mod_mul(&inverse, &fractions[j].1, prime, ctx)?;
+        std::mem::swap(&mut inverse, &mut temp);
+    }
+    results.push(fraction_value(ctx, &fractions[0].0, &inverse, prime)?);
+    results.reverse();
 
-                let mut temp = BigNum::new()?;
-                temp.checked_mul(&denominator, &added, &mut ctx)?;
-                denominator.nnmod(&temp, &prime, &mut ctx)?;
-            }
+    Ok(std::mem::take(&mut results.0))
+}
 
-            let mut working = BigNum::from_dec_str("0")?;
-            working.checked_mul(origin_y, &numerator, &mut ctx)?;
+/// Calculates the j-th chunk polynomial Lagrange interpolation at given point as a single
+/// fraction, returning its numerator and non zero denominator.
+///
+#[inline(always)]
+fn lagrange_fraction(
+    ctx: &mut BigNumContextRef,
+    scratch: &mut ScratchArena,
+    shares_polynomials: &[Vec<Vec<BigNum>>],
+    j: usize,
+    at: &BigNum,
+    prime: &BigNum,
+) -> Result<(BigNum, BigNum), SSSError> {
+    let mut sum_numerator = ClearOnDrop(BigNum::new()?);
+    let mut sum_denominator = BigNum::from_u32(1)?;
+    let [temp, difference, numerator, denominator, term] = scratch.take::<LAGRANGE_SCRATCH>();
+
+    for (i, polys_i) in shares_polynomials.iter().enumerate() {
+        let origin = &polys_i[j][0];
+        let origin_y = &polys_i[j][1];
+        numerator.clear();
+        numerator.add_word(1)?;
+        denominator.clear();
+        denominator.add_word(1)?;
+
+        'k_iter: for (k, polys_k) in shares_polynomials.iter().enumerate() {
+            if k == i {
+                continue 'k_iter;
+            }
 
-            let mut temp = BigNum::new()?;
-            temp.mod_inverse(&denominator, &prime, &mut ctx)?;
-            denominator = temp;
+            let current = &polys_k[j][0];
+            difference.checked_sub(at, current)?;
+            temp.checked_mul(numerator, difference, ctx)?;
+            numerator.nnmod(temp, prime, ctx)?;
 
-            let mut temp = BigNum::new()?;
-            temp.checked_mul(&working, &denominator, &mut ctx)?;
-            working = temp;
+            difference.checked_sub(origin, current)?;
+            temp.checked_mul(denominator, difference, ctx)?;
+            denominator.nnmod(temp, prime, ctx)?;
+        }
 
-            let mut temp = BigNum::new()?;
-            temp.checked_add(&candidate, &working)?;
-            candidate.nnmod(&temp, &prime, &mut ctx)?;
+        if denominator.num_bits() == 0 {
+            let conflicting = shares_polynomials.iter().any(|polys_k| {
+                polys_k[j][0] == *origin
+                    && polys_k
+                        .iter()
+                        .zip(polys_i.iter())
+                        .any(|(k, i)| k[0] == i[0] && k[1] != i[1])
+            });
+            if conflicting {
+                return Err(SSSError::WithReason(
+                    "Shares have the same x-coordinates and different y-coordinates, shares are inconsistent."
+                        .to_owned(),
+                ));
+            }
+            return Err(SSSError::NonInvertible);
         }
 
-        pre_secret_coeffisiances.push(candidate);
+        // sum_numerator / sum_denominator + origin_y * numerator / denominator
+        term.mod_mul(origin_y, numerator, prime, ctx)?;
+        temp.mod_mul(term, &sum_denominator, prime, ctx)?;
+        term.mod_mul(&sum_numerator, denominator, prime, ctx)?;
+        sum_numerator.mod_add(term, temp, prime, ctx)?;
+        temp.mod_mul(&sum_denominator, denominator, prime, ctx)?;
+        std::mem::swap(&mut sum_denominator, temp);
     }
 
-    Ok(big_nums_to_bytes(&pre_secret_coeffisiances))
+    Ok((sum_numerator.to_owned()?, sum_denominator))
+}
+
+/// Multiplies numerator by the inverse of the denominator, asserting the value is in the field.
+/// Value may not fit the chunk bytes, interpolated chunks are checked by chunks_to_bytes and the
+/// chunk width is asserted by big_nums_to_bytes.
+///
+#[inline(always)]
+fn fraction_value(
+    ctx: &mut BigNumContextRef,
+    numerator: &BigNum,
+    denominator_inverse: &BigNum,
+    prime: &BigNum,
+) -> Result<BigNum, SSSError> {
+    let mut candidate = BigNum::new()?;
+    candidate.mod_mul(numerator, denominator_inverse, prime, ctx)?;
+    debug_assert!(candidate < *prime, "interpolated chunk is out of the field");
+
+    Ok(candidate)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        combine_std, create_std,
-        errors::SSSError,
+        combine_inlined, combine_std, create_inlined, create_std,
+        errors::{SSSError, SSSErrorKind},
         operations::secret_bytes_to_hex,
-        shamirss::{combine_shares, create_shares},
+        operations::{
+            big_nums_to_bytes, evaluate_with, legacy_prime, ScratchArena, DEFAULT_PRIME,
+            U8S_TO_BIG_INT_INITIAL,
+        },
+        rng::OpensslRng,
+        shamirss::{
+            combine_all_subsets, combine_shares, combine_shares_checked, combine_shares_indexed,
+            combine_shares_multisecret, combine_shares_prefix, combine_shares_verified,
+            create_multisecret_units, create_shares, create_shares_indexed,
+            create_shares_multisecret, create_shares_with_header, create_shares_with_legacy_prime,
+            create_shares_with_polynomial, create_shares_with_prime, create_shares_with_xs,
+            distinct_random_x, interpolate_at, interpolate_chunk, refresh_shares, repair_share,
+            share_body, shares_to_polynomials, COEFFICIENTS_SIZE, INDEX_SIZE, SHARE_FORMAT_VERSION,
+            SHARE_HEADER_SIZE, THRESHOLD_FORMAT_VERSION, THRESHOLD_HEADER_SIZE,
+        },
     };
+    use openssl::bn::{BigNum, BigNumContext};
     use openssl::rand::rand_bytes;
     use rand::seq::SliceRandom;
-    use rand::thread_rng;
+    use rand::{thread_rng, Rng};
+    use std::{collections::HashSet, time::Instant};
+
+    fn get_random_bytes(size: usize) -> Result<Vec<u8>, SSSError> {
+        let mut bytes = vec![0; size];
+        rand_bytes(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    fn create_v1_shares(min: usize, total: usize, secret: &[u8]) -> Result<Vec<Vec<u8>>, SSSError> {
+        create_shares_with_legacy_prime(min, total, secret)
+    }
+
+    #[test]
+    fn it_should_create_and_combine_std_and_inlined_consistently() -> Result<(), SSSError> {
+        for size in [32, 96, 256] {
+            let secret = get_random_bytes(size)?;
+            let std_shares = create_std(3, 5, &secret)?;
+            let inlined_shares = create_inlined(3, 5, &secret)?;
+
+            assert_eq!(std_shares.len(), inlined_shares.len());
+            for (std_share, inlined_share) in std_shares.iter().zip(inlined_shares.iter()) {
+                assert_eq!(std_share.len(), inlined_share.len());
+                assert_eq!(std_share[0], inlined_share[0]);
+            }
 
-    fn get_random_bytes(size: usize) -> Result<Vec<u8>, SSSError> {
-        let mut bytes = vec![0; size];
-        rand_bytes(&mut bytes)?;
-        Ok(bytes)
+            for shares in [&std_shares, &inlined_shares] {
+                let subset = shares[1..4].to_vec();
+                assert_eq!(combine_std(subset.clone())?, secret);
+                assert_eq!(combine_inlined(subset)?, secret);
+            }
+        }
+
+        assert!(create_std(3, 5, &[1; 33]).is_err());
+        assert!(create_inlined(3, 5, &[1; 33]).is_err());
+
+        Ok(())
     }
 
     #[test]
@@ -313,6 +1885,716 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_should_not_combine_single_share() -> Result<(), SSSError> {
+        let secret = get_random_bytes(128)?;
+        let shares = create_shares(2, 3, &secret)?;
+        let result = combine_shares(shares[0..1].to_vec());
+        assert!(matches!(result, Err(SSSError::WithReason(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_create_shares_with_min_of_one() -> Result<(), SSSError> {
+        let secret = get_random_bytes(128)?;
+        let result = create_shares(1, 3, &secret);
+        assert!(matches!(result, Err(SSSError::WithReason(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_create_shares_when_total_risks_x_collision_for_tiny_prime(
+    ) -> Result<(), SSSError> {
+        let secret = get_random_bytes(32)?;
+        let tiny_prime = BigNum::from_dec_str("4294967311")?;
+        let result = create_shares_with_prime(2, 1000, &secret, &tiny_prime);
+        assert!(matches!(result, Err(SSSError::WithReason(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_create_shares_when_prime_is_smaller_than_chunk() -> Result<(), SSSError> {
+        let secret = get_random_bytes(32)?;
+        let prime_192_bits =
+            BigNum::from_dec_str("6277101735386680763835789423207666416083908700390324961279")?;
+        match create_shares_with_prime(2, 3, &secret, &prime_192_bits) {
+            Err(SSSError::WithReason(reason)) => {
+                assert_eq!(
+                    reason,
+                    "prime too small for chunk size; secret values may exceed the field"
+                )
+            }
+            _ => panic!("expected prime smaller than chunk to be rejected"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_create_shares_when_prime_has_chunk_bit_length() -> Result<(), SSSError> {
+        let secret = vec![0xff; 32];
+        let prime_256_bits = legacy_prime()?;
+        assert_eq!(prime_256_bits.num_bits(), 256);
+        match create_shares_with_prime(2, 3, &secret, prime_256_bits) {
+            Err(SSSError::WithReason(reason)) => {
+                assert_eq!(
+                    reason,
+                    "prime too small for chunk size; secret values may exceed the field"
+                )
+            }
+            _ => panic!("expected prime of chunk bit length to be rejected"),
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn it_should_create_shares_in_parallel() -> Result<(), SSSError> {
+        use crate::shamirss::create_shares_parallel;
+
+        let secret = get_random_bytes(512)?;
+        let shares = create_shares_parallel(10, 20, &secret)?;
+        assert_eq!(shares.len(), 20);
+        assert_eq!(combine_shares(shares[..10].to_vec())?, secret);
+        assert_eq!(combine_shares(shares[7..17].to_vec())?, secret);
+
+        let polynomials = shares_to_polynomials(&shares)?;
+        for j in 0..secret.len() / U8S_TO_BIG_INT_INITIAL {
+            let xs: HashSet<Vec<u8>> = polynomials.iter().map(|p| p[j][0].to_vec()).collect();
+            assert_eq!(xs.len(), shares.len());
+        }
+        assert!(create_shares_parallel(1, 20, &secret).is_err());
+        assert!(create_shares_parallel(21, 20, &secret).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn it_should_combine_shares_in_parallel_same_as_serial() -> Result<(), SSSError> {
+        use crate::shamirss::combine_shares_parallel;
+
+        let secret = get_random_bytes(512)?;
+        let shares = create_shares(10, 20, &secret)?;
+        let serial = combine_shares(shares.clone())?;
+        let parallel = combine_shares_parallel(shares)?;
+        assert_eq!(serial, parallel);
+        assert_eq!(parallel, secret);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn it_should_combine_shares_of_every_format_in_parallel_same_as_serial() -> Result<(), SSSError>
+    {
+        use crate::shamirss::combine_shares_parallel;
+
+        let secret = get_random_bytes(512)?;
+        let v1_shares = create_v1_shares(10, 20, &secret)?;
+        let legacy_shares: Vec<Vec<u8>> = v1_shares
+            .iter()
+            .map(|share| share[SHARE_HEADER_SIZE..].to_vec())
+            .collect();
+        let header_shares = create_shares_with_header(10, 20, &secret)?;
+        for shares in [v1_shares, legacy_shares, header_shares] {
+            let serial = combine_shares(shares[5..15].to_vec())?;
+            let parallel = combine_shares_parallel(shares[5..15].to_vec())?;
+            assert_eq!(serial, parallel);
+            assert_eq!(parallel, secret);
+            assert!(combine_shares_parallel(shares[..1].to_vec()).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_refresh_shares_keeping_the_secret() -> Result<(), SSSError> {
+        let secret = get_random_bytes(96)?;
+        for old in [
+            create_shares(3, 5, &secret)?,
+            create_v1_shares(3, 5, &secret)?,
+            create_shares_with_header(3, 5, &secret)?,
+        ] {
+            let new = refresh_shares(old.clone(), 3)?;
+            assert_eq!(new.len(), old.len());
+            assert_eq!(new[0][..SHARE_HEADER_SIZE], old[0][..SHARE_HEADER_SIZE]);
+            assert_eq!(combine_shares(new[..3].to_vec())?, secret);
+            assert_eq!(combine_shares(new[2..].to_vec())?, secret);
+
+            let old_polynomials = shares_to_polynomials(&old)?;
+            let new_polynomials = shares_to_polynomials(&new)?;
+            for j in 0..3 {
+                assert!(new_polynomials
+                    .iter()
+                    .all(|n| old_polynomials.iter().all(|o| o[j][0] != n[j][0])));
+            }
+
+            let mixed = vec![old[0].clone(), old[1].clone(), new[2].clone()];
+            assert_ne!(combine_shares(mixed)?, secret);
+        }
+        assert!(refresh_shares(create_shares(3, 5, &secret)?[..2].to_vec(), 3).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_injected_zero_x() -> Result<(), SSSError> {
+        let mut ctx = BigNumContext::new()?;
+        let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+        let secret = get_random_bytes(64)?;
+        match create_shares_with_xs(
+            &mut ctx,
+            &mut OpensslRng,
+            2,
+            3,
+            &secret,
+            &prime,
+            |_, share, chunk| Ok(BigNum::from_u32((share * 10 + chunk) as u32)?),
+        ) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.starts_with("x-coordinate")),
+            _ => panic!("expected zero x-coordinate to be rejected"),
+        }
+
+        let shares = create_shares(2, 50, &secret)?;
+        for share in shares.iter() {
+            for chunk in share[SHARE_HEADER_SIZE..].chunks(COEFFICIENTS_SIZE) {
+                assert!(chunk[..U8S_TO_BIG_INT_INITIAL].iter().any(|b| *b != 0));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_draw_distinct_x_coordinates() -> Result<(), SSSError> {
+        let small_prime = BigNum::from_u32(7)?;
+        let mut used = HashSet::new();
+        for _ in 1..7 {
+            distinct_random_x(&mut OpensslRng, &mut used, &small_prime)?;
+        }
+        let expected: HashSet<Vec<u8>> = (1..7u8).map(|x| vec![x]).collect();
+        assert_eq!(used, expected);
+
+        let secret = get_random_bytes(64)?;
+        let shares = create_shares(2, 200, &secret)?;
+        let polynomials = shares_to_polynomials(&shares)?;
+        for j in 0..2 {
+            let xs: HashSet<Vec<u8>> = polynomials.iter().map(|p| p[j][0].to_vec()).collect();
+            assert_eq!(xs.len(), shares.len());
+        }
+        for pair in shares.chunks(2) {
+            assert_eq!(combine_shares(pair.to_vec())?, secret);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_fail_combining_shares_with_injected_x_collision() -> Result<(), SSSError> {
+        let mut ctx = BigNumContext::new()?;
+        let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+        let secret = get_random_bytes(64)?;
+        let shares = create_shares_with_xs(
+            &mut ctx,
+            &mut OpensslRng,
+            2,
+            3,
+            &secret,
+            &prime,
+            |_, share, chunk| Ok(BigNum::from_u32((share.min(1) * 10 + chunk + 1) as u32)?),
+        )?;
+        let mut colliding = shares[1..3].to_vec();
+        let last = colliding[1].len() - 1;
+        colliding[1][last - U8S_TO_BIG_INT_INITIAL] ^= 1;
+        colliding[1][last] ^= 1;
+        match combine_shares(colliding) {
+            Err(e @ SSSError::NonInvertible) => assert_eq!(e.kind(), SSSErrorKind::NonInvertible),
+            other => panic!(
+                "expected combining shares with the same x-coordinate to fail, got {other:?}"
+            ),
+        }
+        assert_eq!(combine_shares(shares[0..2].to_vec())?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_panic_combining_random_bytes() -> Result<(), SSSError> {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let shares: Vec<Vec<u8>> = (0..rng.gen_range(0..5))
+                .map(|_| get_random_bytes(rng.gen_range(0..300)))
+                .collect::<Result<_, _>>()?;
+            let _ = combine_shares(shares);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_versioned_and_legacy_shares() -> Result<(), SSSError> {
+        let secret = get_random_bytes(256)?;
+        let shares = create_shares(3, 5, &secret)?;
+        for share in shares.iter() {
+            assert_eq!(share[0], SHARE_FORMAT_VERSION);
+            assert_eq!(share.len(), SHARE_HEADER_SIZE + 8 * COEFFICIENTS_SIZE);
+        }
+        assert_eq!(combine_shares(shares.clone())?, secret);
+
+        let v1_shares = create_v1_shares(3, 5, &secret)?;
+        assert_eq!(combine_shares(v1_shares.clone())?, secret);
+        let legacy: Vec<Vec<u8>> = v1_shares
+            .iter()
+            .map(|s| s[SHARE_HEADER_SIZE..].to_vec())
+            .collect();
+        assert_eq!(combine_shares(legacy.clone())?, secret);
+
+        let mixed = vec![legacy[0].clone(), v1_shares[1].clone(), legacy[2].clone()];
+        assert_eq!(combine_shares(mixed)?, secret);
+
+        let mixed_primes = vec![legacy[0].clone(), shares[1].clone(), shares[2].clone()];
+        match combine_shares(mixed_primes) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("older format versions")),
+            _ => panic!("expected version 2 shares mixed with legacy shares to be rejected"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_combine_shares_of_unsupported_version() -> Result<(), SSSError> {
+        let secret = get_random_bytes(128)?;
+        let mut shares = create_shares(2, 3, &secret)?;
+        shares[1][0] = THRESHOLD_FORMAT_VERSION + 1;
+        match combine_shares(shares) {
+            Err(SSSError::WithReason(reason)) => {
+                assert!(reason.starts_with("unsupported share format version"))
+            }
+            _ => panic!("expected unsupported share format version error"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_combine_shares_holding_header_only() -> Result<(), SSSError> {
+        let secret = get_random_bytes(64)?;
+        let mut shares = create_shares(2, 3, &secret)?;
+        shares[1].truncate(SHARE_HEADER_SIZE);
+        match combine_shares(shares) {
+            Err(SSSError::WithReason(reason)) => {
+                assert_eq!(reason, "share contains no secret data after header")
+            }
+            _ => panic!("expected header only share to be rejected"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_shares_with_header_and_combine_checked() -> Result<(), SSSError> {
+        let secret = get_random_bytes(96)?;
+        let shares = create_shares_with_header(3, 5, &secret)?;
+        for share in shares.iter() {
+            assert_eq!(
+                share[..THRESHOLD_HEADER_SIZE],
+                [THRESHOLD_FORMAT_VERSION, 3]
+            );
+            assert_eq!(share.len(), THRESHOLD_HEADER_SIZE + 3 * COEFFICIENTS_SIZE);
+        }
+        assert_eq!(combine_shares_checked(shares[2..].to_vec())?, secret);
+        assert_eq!(combine_shares(shares[..3].to_vec())?, secret);
+
+        let mut damaged = shares[4].clone();
+        damaged[THRESHOLD_HEADER_SIZE + U8S_TO_BIG_INT_INITIAL] ^= 1;
+        assert_eq!(repair_share(shares[..3].to_vec(), &damaged, 3)?, shares[4]);
+
+        for too_few in [
+            shares[..2].to_vec(),
+            vec![shares[0].clone(), shares[1].clone(), shares[0].clone()],
+        ] {
+            match combine_shares_checked(too_few) {
+                Err(SSSError::WithReason(reason)) => {
+                    assert!(reason.starts_with("insufficient shares"))
+                }
+                _ => panic!("expected insufficient shares error"),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_combine_checked_shares_without_matching_header() -> Result<(), SSSError> {
+        let secret = get_random_bytes(64)?;
+        let shares = create_shares(2, 3, &secret)?;
+        assert!(combine_shares_checked(shares.clone()).is_err());
+
+        let mut shares = create_shares_with_header(2, 3, &secret)?;
+        shares[1][1] = 3;
+        assert!(combine_shares_checked(shares).is_err());
+
+        assert!(create_shares_with_header(256, 300, &secret).is_err());
+        assert!(share_body(&[THRESHOLD_FORMAT_VERSION]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_shares_and_combine_shares_with_final_zero_chunk() -> Result<(), SSSError> {
+        let mut secret = get_random_bytes(U8S_TO_BIG_INT_INITIAL)?;
+        secret.extend([0; U8S_TO_BIG_INT_INITIAL]);
+        let shares = create_shares(3, 5, &secret)?;
+        let secret_decoded = combine_shares(shares[1..4].to_vec())?;
+        assert_eq!(secret_decoded.len(), 2 * U8S_TO_BIG_INT_INITIAL);
+        assert_eq!(secret_decoded, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_all_subsets_when_they_agree() -> Result<(), SSSError> {
+        let secret = get_random_bytes(96)?;
+        let shares = create_shares(3, 5, &secret)?;
+        assert_eq!(combine_all_subsets(&shares, 3, 10, None)?, secret);
+        assert!(combine_all_subsets(&shares, 3, 9, None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_time_out_combining_all_subsets_after_deadline() -> Result<(), SSSError> {
+        let secret = get_random_bytes(32)?;
+        let shares = create_shares(10, 20, &secret)?;
+        let deadline = Instant::now() + std::time::Duration::from_millis(1);
+        match combine_all_subsets(&shares, 10, usize::MAX, Some(deadline)) {
+            Err(SSSError::WithReason(reason)) => {
+                assert_eq!(reason, "reconstruction cancelled/timed out")
+            }
+            _ => panic!("expected subsets search to time out"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_list_disagreeing_subsets_for_corrupted_share() -> Result<(), SSSError> {
+        let secret = get_random_bytes(96)?;
+        let mut shares = create_shares(3, 5, &secret)?;
+        let last = shares[4].len() - 1;
+        shares[4][last] ^= 1;
+        match combine_all_subsets(&shares, 3, 10, None) {
+            Err(SSSError::WithReason(reason)) => {
+                assert!(reason.contains("[0, 1, 4]"));
+                assert!(!reason.contains("[0, 1, 2]"));
+            }
+            _ => panic!("expected subsets with the corrupted share to disagree"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_and_verify_held_out_shares() -> Result<(), SSSError> {
+        let secret = get_random_bytes(96)?;
+        let mut shares = create_shares(3, 5, &secret)?;
+        assert_eq!(combine_shares_verified(shares.clone(), 3)?, secret);
+        assert!(combine_shares_verified(shares[..3].to_vec(), 3).is_err());
+
+        let last = shares[4].len() - 1;
+        shares[4][last] ^= 1;
+        match combine_shares_verified(shares, 3) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("index 4")),
+            _ => panic!("expected inconsistent held-out share to fail verification"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_and_combine_multisecret_shares() -> Result<(), SSSError> {
+        let keys = [
+            get_random_bytes(32)?,
+            get_random_bytes(32)?,
+            get_random_bytes(32)?,
+        ];
+        let secrets: Vec<&[u8]> = keys.iter().map(|k| k.as_slice()).collect();
+        let shares = create_shares_multisecret(3, 5, &secrets)?;
+        assert_eq!(shares.len(), 5);
+        assert_eq!(shares[0].len(), 2 + 4 * U8S_TO_BIG_INT_INITIAL);
+        assert_eq!(shares[0][..2], [SHARE_FORMAT_VERSION, 3]);
+
+        let recreated = combine_shares_multisecret(shares[2..].to_vec())?;
+        assert_eq!(recreated, keys.to_vec());
+
+        let uneven: Vec<&[u8]> = vec![&keys[0], &keys[1][..16]];
+        assert!(create_shares_multisecret(3, 5, &uneven).is_err());
+        assert!(create_shares_multisecret(3, 5, &[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_and_combine_multisecret_shares_of_all_ones_chunk() -> Result<(), SSSError> {
+        let keys = [vec![0xff; 64], get_random_bytes(64)?];
+        let secrets: Vec<&[u8]> = keys.iter().map(|k| k.as_slice()).collect();
+        for _ in 0..8 {
+            let shares = create_shares_multisecret(2, 3, &secrets)?;
+            assert_eq!(
+                combine_shares_multisecret(shares[1..].to_vec())?,
+                keys.to_vec()
+            );
+        }
+
+        let legacy = create_multisecret_units(2, 3, &secrets[1..], legacy_prime()?, &[1])?;
+        assert_eq!(legacy[0].len(), 1 + 2 * 2 * U8S_TO_BIG_INT_INITIAL);
+        assert_eq!(
+            combine_shares_multisecret(legacy[..2].to_vec())?,
+            keys[1..].to_vec()
+        );
+
+        let mut mixed = create_shares_multisecret(2, 3, &secrets[1..])?;
+        mixed[1] = legacy[1].clone();
+        assert!(combine_shares_multisecret(mixed).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "high-security")]
+    #[test]
+    fn it_should_create_and_combine_shares_with_per_operation_contexts() -> Result<(), SSSError> {
+        let secret = get_random_bytes(256)?;
+        let shares = create_shares(4, 7, &secret)?;
+        assert_eq!(combine_shares(shares[3..].to_vec())?, secret);
+        assert_eq!(combine_shares_verified(shares, 4)?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_create_shares_and_combine_shares_of_all_ones_chunk() -> Result<(), SSSError> {
+        let secret = vec![0xff; 2 * U8S_TO_BIG_INT_INITIAL];
+        for _ in 0..20 {
+            let shares = create_shares(3, 5, &secret)?;
+            for share in shares.iter() {
+                assert_eq!(share.len(), SHARE_HEADER_SIZE + 2 * COEFFICIENTS_SIZE);
+            }
+            assert_eq!(combine_shares(shares[2..].to_vec())?, secret);
+        }
+
+        let v1_shares = create_v1_shares(3, 5, &secret)?;
+        assert_ne!(combine_shares(v1_shares[2..].to_vec())?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_interpolate_chunks_fitting_in_field_bytes() -> Result<(), SSSError> {
+        let mut ctx = BigNumContext::new()?;
+        let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+        let mut high_chunk = [0xff; U8S_TO_BIG_INT_INITIAL];
+        high_chunk[U8S_TO_BIG_INT_INITIAL - 1] = 0;
+        let mut secret = high_chunk.repeat(8);
+        secret.extend(get_random_bytes(8 * U8S_TO_BIG_INT_INITIAL)?);
+        let shares = create_shares(3, 5, &secret)?;
+        let shares_polynomials = shares_to_polynomials(&shares[..3])?;
+        for j in 0..16 {
+            let chunk = interpolate_chunk(&mut ctx, &shares_polynomials, j, &prime)?;
+            assert!(chunk < prime);
+            assert!(chunk.num_bytes() as usize <= U8S_TO_BIG_INT_INITIAL);
+        }
+        assert_eq!(combine_shares(shares[..3].to_vec())?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_forged_shares_interpolating_chunk_above_share_bytes() -> Result<(), SSSError>
+    {
+        // Points (1, 5), (2, 306) and (3, 607) lie on f(x) = 2^256 + 1 + (4 - 2^256) * x, so
+        // f(0) is in the field of the default prime 2^256 + 297, but does not fit 32 bytes.
+        let point = |x: u32, y: u32| -> Result<Vec<u8>, SSSError> {
+            Ok(big_nums_to_bytes(&[
+                BigNum::from_u32(x)?,
+                BigNum::from_u32(y)?,
+            ]))
+        };
+        let forged: Vec<Vec<u8>> = [(1, 5), (2, 306), (3, 607)]
+            .iter()
+            .map(|(x, y)| {
+                let mut share = vec![SHARE_FORMAT_VERSION];
+                share.extend(point(*x, *y)?);
+                Ok(share)
+            })
+            .collect::<Result<_, SSSError>>()?;
+        let reason = format!("Interpolated value does not fit {U8S_TO_BIG_INT_INITIAL} bytes.");
+        let assert_rejected = |result: Result<Vec<u8>, SSSError>| match result {
+            Err(SSSError::WithReason(r)) => assert_eq!(r, reason),
+            other => panic!("expected interpolated value to be rejected, got {other:?}"),
+        };
+
+        assert_rejected(combine_shares(forged[..2].to_vec()));
+        assert_rejected(combine_shares_verified(forged.clone(), 2));
+        #[cfg(feature = "rayon")]
+        assert_rejected(crate::shamirss::combine_shares_parallel(
+            forged[..2].to_vec(),
+        ));
+
+        let indexed: Vec<Vec<u8>> = forged
+            .iter()
+            .map(|share| {
+                let mut indexed = vec![share[SHARE_HEADER_SIZE + U8S_TO_BIG_INT_INITIAL - 1]];
+                indexed.extend(&share[SHARE_HEADER_SIZE + U8S_TO_BIG_INT_INITIAL..]);
+                indexed
+            })
+            .collect();
+        assert_rejected(combine_shares_indexed(indexed[..2].to_vec()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_interpolate_at_point_as_evaluated_polynomial() -> Result<(), SSSError> {
+        let mut secret = vec![0; 96];
+        rand_bytes(&mut secret)?;
+        let (shares, polynomial) =
+            create_shares_with_polynomial(3, 5, &secret, |_, polynomial| {
+                polynomial
+                    .iter()
+                    .map(|coefficients| {
+                        coefficients
+                            .iter()
+                            .map(|c| c.as_ref().to_owned())
+                            .collect::<Result<Vec<BigNum>, _>>()
+                    })
+                    .collect::<Result<Vec<Vec<BigNum>>, _>>()
+                    .map_err(SSSError::from)
+            })?;
+
+        let mut ctx = BigNumContext::new()?;
+        let mut scratch = ScratchArena::new(1)?;
+        let x = BigNum::from_u32(12345)?;
+        let prime = BigNum::from_dec_str(DEFAULT_PRIME)?;
+        let expected = polynomial
+            .iter()
+            .map(|coefficients| evaluate_with(&mut ctx, &mut scratch, coefficients, &x, &prime))
+            .collect::<Result<Vec<BigNum>, _>>()?;
+
+        assert_eq!(
+            interpolate_at(&shares[..3], &x)?,
+            big_nums_to_bytes(&expected)
+        );
+        assert_eq!(
+            interpolate_at(&shares[2..], &x)?,
+            big_nums_to_bytes(&expected)
+        );
+        assert_eq!(interpolate_at(&shares[1..4], &BigNum::new()?)?, secret);
+        assert_eq!(combine_shares(shares[1..4].to_vec())?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_no_shares_provided() -> Result<(), SSSError> {
+        for shares in [Vec::new(), vec![Vec::new(), Vec::new()]] {
+            match combine_shares(shares.clone()) {
+                Err(SSSError::WithReason(reason)) => assert_eq!(reason, "no shares provided"),
+                other => panic!("expected no shares provided error, got {other:?}"),
+            }
+            assert!(combine_shares_indexed(shares).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_any_subset_of_indexed_shares() -> Result<(), SSSError> {
+        let mut secret = vec![0; 96];
+        rand_bytes(&mut secret)?;
+        let shares = create_shares_indexed(3, 6, &secret)?;
+        for (i, share) in shares.iter().enumerate() {
+            assert_eq!(share[0] as usize, i + 1);
+            assert_eq!(share.len(), INDEX_SIZE + secret.len());
+        }
+        assert_eq!(combine_shares_indexed(shares[..3].to_vec())?, secret);
+        assert_eq!(
+            combine_shares_indexed(vec![
+                shares[5].clone(),
+                shares[1].clone(),
+                shares[3].clone()
+            ])?,
+            secret
+        );
+        assert_ne!(combine_shares_indexed(shares[..2].to_vec())?, secret);
+
+        let mut conflicting = shares[0].clone();
+        conflicting[INDEX_SIZE] ^= 1;
+        assert!(
+            combine_shares_indexed(vec![shares[0].clone(), conflicting, shares[1].clone()])
+                .is_err()
+        );
+        assert!(combine_shares_indexed(vec![shares[0].clone(), shares[1][..33].to_vec()]).is_err());
+        assert!(create_shares_indexed(2, 256, &secret).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_dedupe_exact_duplicate_shares() -> Result<(), SSSError> {
+        let secret = get_random_bytes(96)?;
+        let shares = create_v1_shares(3, 5, &secret)?;
+        let duplicated = vec![
+            shares[0].clone(),
+            shares[1].clone(),
+            shares[0][SHARE_HEADER_SIZE..].to_vec(),
+            shares[2].clone(),
+            shares[1].clone(),
+        ];
+        assert_eq!(combine_shares(duplicated)?, secret);
+        assert!(combine_shares(vec![shares[0].clone(), shares[0].clone()]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_same_x_with_different_y() -> Result<(), SSSError> {
+        let secret = get_random_bytes(64)?;
+        let shares = create_shares(2, 3, &secret)?;
+        let mut conflicting = shares[0].clone();
+        let last = conflicting.len() - 1;
+        conflicting[last] ^= 1;
+        match combine_shares(vec![shares[0].clone(), conflicting, shares[1].clone()]) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("inconsistent")),
+            _ => panic!("expected conflicting y-coordinates to be rejected"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_common_prefix_of_partial_shares() -> Result<(), SSSError> {
+        let secret = get_random_bytes(8 * U8S_TO_BIG_INT_INITIAL)?;
+        let shares = create_v1_shares(3, 5, &secret)?;
+        let partial = vec![
+            shares[0].clone(),
+            shares[1][SHARE_HEADER_SIZE..].to_vec(),
+            shares[2][..SHARE_HEADER_SIZE + 4 * COEFFICIENTS_SIZE].to_vec(),
+        ];
+        assert!(combine_shares(partial.clone()).is_err());
+        assert_eq!(
+            combine_shares_prefix(partial)?,
+            secret[..4 * U8S_TO_BIG_INT_INITIAL].to_vec()
+        );
+        assert_eq!(combine_shares_prefix(shares[2..].to_vec())?, secret);
+
+        Ok(())
+    }
+
     // This brute force test tries to guess the coefficient and brake secret with small amount of
     // shares available.
     #[ignore]
@@ -334,8 +2616,8 @@ mod tests {
                 if secret == secret_decoded {
                     println!(
                         "Successful guess after {counter} tries\nSecret:  {}\nDecoded: {}\n",
-                        secret_bytes_to_hex(&secret),
-                        secret_bytes_to_hex(&secret_decoded)
+                        secret_bytes_to_hex(&secret, false),
+                        secret_bytes_to_hex(&secret_decoded, false)
                     );
                     return Err(SSSError::WithReason("Share brute forced.".to_string()));
                 }
@@ -372,8 +2654,8 @@ mod tests {
                 if secret == secret_decoded {
                     println!(
                         "Successful guess after {counter} tries\nSecret:  {}\nDecoded: {}\n",
-                        secret_bytes_to_hex(&secret),
-                        secret_bytes_to_hex(&secret_decoded)
+                        secret_bytes_to_hex(&secret, false),
+                        secret_bytes_to_hex(&secret_decoded, false)
                     );
                     return Err(SSSError::WithReason("Share brute forced.".to_string()));
                 }
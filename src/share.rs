@@ -0,0 +1,191 @@
+use crate::{errors::SSSError, shamirss::share_body};
+#[cfg(feature = "serde")]
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// Share is a validated share bytes, including the share format version byte if present.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share(Vec<u8>);
+
+impl Share {
+    /// Creates share from given bytes.
+    /// Bytes shall be a versioned or legacy share with at least one chunk in the share body.
+    ///
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SSSError> {
+        share_body(bytes)?;
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// Returns all share bytes, including the share format version byte if present.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Consumes share returning all share bytes.
+    ///
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+/// Returns all share bytes, including the share format version byte if present.
+///
+impl AsRef<[u8]> for Share {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Share {
+    type Error = SSSError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<Share> for Vec<u8> {
+    fn from(share: Share) -> Self {
+        share.0
+    }
+}
+
+/// ShareSet is a set of validated shares of a single secret.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareSet(Vec<Share>);
+
+impl ShareSet {
+    /// Creates share set from given shares bytes, validating each share.
+    ///
+    pub fn from_bytes(shares: &[Vec<u8>]) -> Result<Self, SSSError> {
+        Ok(Self(
+            shares
+                .iter()
+                .map(|bytes| Share::from_bytes(bytes))
+                .collect::<Result<Vec<Share>, SSSError>>()?,
+        ))
+    }
+
+    /// Returns shares in the set.
+    ///
+    pub fn shares(&self) -> &[Share] {
+        &self.0
+    }
+
+    /// Consumes share set returning bytes of all the shares.
+    ///
+    pub fn into_bytes(self) -> Vec<Vec<u8>> {
+        self.0.into_iter().map(Share::into_bytes).collect()
+    }
+}
+
+impl From<Vec<Share>> for ShareSet {
+    fn from(shares: Vec<Share>) -> Self {
+        Self(shares)
+    }
+}
+
+/// Share is serialized as a standard base64 string.
+///
+#[cfg(feature = "serde")]
+impl serde::Serialize for Share {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(&self.0))
+    }
+}
+
+/// Share is deserialized from a standard base64 string, decoded bytes are validated.
+///
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Share {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let encoded = String::deserialize(deserializer)?;
+        let bytes = STANDARD.decode(encoded).map_err(D::Error::custom)?;
+        Self::from_bytes(&bytes).map_err(D::Error::custom)
+    }
+}
+
+/// ShareSet is serialized as a sequence of shares.
+///
+#[cfg(feature = "serde")]
+impl serde::Serialize for ShareSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ShareSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(Vec::<Share>::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_std;
+
+    #[test]
+    fn it_should_convert_share_from_and_to_bytes() -> Result<(), SSSError> {
+        let shares = create_std(2, 3, &[5; 64])?;
+        for bytes in shares.iter() {
+            let share = Share::from_bytes(bytes)?;
+            assert_eq!(share.as_bytes(), bytes.as_slice());
+            assert_eq!(share.as_ref(), bytes.as_slice());
+
+            let share = Share::try_from(bytes.as_slice())?;
+            assert_eq!(Vec::<u8>::from(share), *bytes);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_create_share_from_bytes_of_invalid_length() -> Result<(), SSSError> {
+        let shares = create_std(2, 3, &[5; 64])?;
+        assert!(Share::from_bytes(&shares[0][..shares[0].len() - 2]).is_err());
+        assert!(Share::from_bytes(&shares[0][..1]).is_err());
+        assert!(Share::from_bytes(&[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_share_set_from_and_to_bytes() -> Result<(), SSSError> {
+        let shares = create_std(2, 3, &[5; 64])?;
+        let set = ShareSet::from_bytes(&shares)?;
+        assert_eq!(set.shares().len(), 3);
+        assert_eq!(set.into_bytes(), shares);
+
+        let mut shares = shares;
+        shares[1].truncate(1);
+        assert!(ShareSet::from_bytes(&shares).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_should_serialize_share_set_to_json_and_combine() -> Result<(), SSSError> {
+        use crate::{combine_typed, create_shares_typed};
+
+        let secret = [9; 96];
+        let set = create_shares_typed(3, 5, &secret)?;
+        let json = serde_json::to_string(&set).unwrap();
+        assert!(json.starts_with("[\""));
+
+        let decoded: ShareSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, set);
+        assert_eq!(combine_typed(decoded)?, secret);
+
+        assert!(serde_json::from_str::<ShareSet>("[\"not base64!\"]").is_err());
+        assert!(serde_json::from_str::<ShareSet>("[\"AQ==\"]").is_err());
+
+        Ok(())
+    }
+}
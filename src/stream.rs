@@ -0,0 +1,334 @@
+use crate::{
+    errors::SSSError,
+    operations::{default_prime, wipe_bytes, U8S_TO_BIG_INT_INITIAL},
+    rng::OpensslRng,
+    shamirss::{
+        combine_shares_with_context, create_shares_with_context, validate_create_args,
+        COEFFICIENTS_SIZE, SHARE_FORMAT_VERSION, SHARE_HEADER_SIZE,
+    },
+};
+use openssl::bn::BigNumContext;
+use std::io::{Read, Write};
+
+/// Size in bytes of the secret block read and shared at once.
+///
+const STREAM_BLOCK_SIZE: usize = 1024 * U8S_TO_BIG_INT_INITIAL;
+
+/// Size in bytes of the secret length trailer closing the streamed secret.
+///
+const LENGTH_TRAILER_SIZE: usize = 8;
+
+/// Size in bytes of the share body of a single secret block.
+///
+const SHARE_BLOCK_SIZE: usize = STREAM_BLOCK_SIZE / U8S_TO_BIG_INT_INITIAL * COEFFICIENTS_SIZE;
+
+/// Count of the last recreated bytes held back until the end of shares, enough to cover the
+/// zero padding and the length trailer.
+///
+const HOLD_BACK_SIZE: usize = U8S_TO_BIG_INT_INITIAL + LENGTH_TRAILER_SIZE;
+
+/// Splits the secret read from given reader to shares written incrementally to given writers.
+/// Secret is read in blocks, so it does not need to fit in memory. The last block is zero padded
+/// and closed with the 8 bytes big-endian secret length, so secret of any size can be split.
+/// Every writer receives the share format version byte followed by the share body of every block,
+/// the i-th writer receives the i-th share. Arguments are validated before anything is written.
+///
+/// # Argument
+///
+/// * `min`      - minimal amount of shares required to recreate the secret.
+/// * `total`    - total amount of shares, equal to the writers count.
+/// * `reader`   - reader of the secret.
+/// * `writers`  - writers of the shares.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::split_reader;
+///
+///let secret = vec![7; 100];
+///let mut writers = vec![Vec::new(); 3];
+///split_reader(2, 3, secret.as_slice(), &mut writers).unwrap();
+///assert!(writers.iter().all(|share| share.len() == 1 + 2 * 128));
+///```
+///
+pub fn split_reader<R: Read, W: Write>(
+    min: usize,
+    total: usize,
+    mut reader: R,
+    writers: &mut [W],
+) -> Result<(), SSSError> {
+    if writers.len() != total {
+        return Err(SSSError::WithReason(format!(
+            "Writers count {} shall be equal to total shares {total}.",
+            writers.len()
+        )));
+    }
+    let mut ctx = BigNumContext::new()?;
+    let prime = default_prime()?;
+    validate_create_args(&mut ctx, min, total, prime)?;
+    for writer in writers.iter_mut() {
+        writer.write_all(&[SHARE_FORMAT_VERSION])?;
+    }
+
+    let mut block = vec![0u8; STREAM_BLOCK_SIZE + U8S_TO_BIG_INT_INITIAL];
+    let mut length: u64 = 0;
+    let result = loop {
+        let filled = match read_block(&mut reader, &mut block[..STREAM_BLOCK_SIZE]) {
+            Ok(filled) => filled,
+            Err(e) => break Err(e),
+        };
+        length += filled as u64;
+        let last = filled < STREAM_BLOCK_SIZE;
+        let size = if last {
+            let size = (filled + LENGTH_TRAILER_SIZE).div_ceil(U8S_TO_BIG_INT_INITIAL)
+                * U8S_TO_BIG_INT_INITIAL;
+            block[filled..size - LENGTH_TRAILER_SIZE].fill(0);
+            block[size - LENGTH_TRAILER_SIZE..size].copy_from_slice(&length.to_be_bytes());
+            size
+        } else {
+            filled
+        };
+
+        let shares = match create_shares_with_context(
+            &mut ctx,
+            &mut OpensslRng,
+            min,
+            total,
+            &block[..size],
+            prime,
+        ) {
+            Ok(shares) => shares,
+            Err(e) => break Err(e),
+        };
+        if let Err(e) = write_shares(writers, &shares) {
+            break Err(e);
+        }
+        if last {
+            break Ok(());
+        }
+    };
+    wipe_bytes(&mut block);
+
+    result
+}
+
+/// Combines shares read from given readers to the secret written incrementally to given writer.
+/// Shares are read block by block, so neither the shares nor the secret need to fit in memory.
+/// Shares shall be created with split_reader, the padding and length trailer are stripped.
+/// Readers of different lengths are rejected with the index of the block they differ at.
+///
+/// # Argument
+///
+/// * `readers`  - readers of the shares, equal or more the minimal share count.
+/// * `out`      - writer of the recreated secret.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_readers, split_reader};
+///
+///let secret = vec![7; 100];
+///let mut writers = vec![Vec::new(); 3];
+///split_reader(2, 3, secret.as_slice(), &mut writers).unwrap();
+///let mut readers: Vec<&[u8]> = writers[1..].iter().map(|share| share.as_slice()).collect();
+///let mut recreated = Vec::new();
+///combine_readers(&mut readers, &mut recreated).unwrap();
+///assert_eq!(secret, recreated);
+///```
+///
+pub fn combine_readers<R: Read, W: Write>(readers: &mut [R], mut out: W) -> Result<(), SSSError> {
+    if readers.is_empty() {
+        return Err(SSSError::WithReason("no shares provided".to_owned()));
+    }
+    let mut shares: Vec<Vec<u8>> =
+        vec![vec![0u8; SHARE_HEADER_SIZE + SHARE_BLOCK_SIZE]; readers.len()];
+    let mut carry: Vec<u8> = Vec::with_capacity(STREAM_BLOCK_SIZE + HOLD_BACK_SIZE);
+    let result = combine_blocks(readers, &mut out, &mut shares, &mut carry);
+    wipe_bytes(&mut carry);
+
+    result
+}
+
+/// Combines share blocks writing the secret, all but the last HOLD_BACK_SIZE recreated bytes
+/// are written as soon as recreated.
+///
+#[inline(always)]
+fn combine_blocks<R: Read, W: Write>(
+    readers: &mut [R],
+    out: &mut W,
+    shares: &mut [Vec<u8>],
+    carry: &mut Vec<u8>,
+) -> Result<(), SSSError> {
+    let mut ctx = BigNumContext::new()?;
+    for (i, reader) in readers.iter_mut().enumerate() {
+        let mut header = [0u8; SHARE_HEADER_SIZE];
+        if read_block(reader, &mut header)? != SHARE_HEADER_SIZE
+            || header[0] != SHARE_FORMAT_VERSION
+        {
+            return Err(SSSError::WithReason(format!(
+                "Share reader at index {i} does not start with share format version {SHARE_FORMAT_VERSION}."
+            )));
+        }
+    }
+
+    let mut written: u64 = 0;
+    for index in 0.. {
+        let mut size = None;
+        for (reader, share) in readers.iter_mut().zip(shares.iter_mut()) {
+            share.resize(SHARE_HEADER_SIZE + SHARE_BLOCK_SIZE, 0);
+            share[0] = SHARE_FORMAT_VERSION;
+            let filled = read_block(reader, &mut share[SHARE_HEADER_SIZE..])?;
+            share.truncate(SHARE_HEADER_SIZE + filled);
+            if *size.get_or_insert(filled) != filled || filled % COEFFICIENTS_SIZE != 0 {
+                return Err(SSSError::WithReason(format!(
+                    "Share readers differ in length at block {index}."
+                )));
+            }
+        }
+        if size == Some(0) {
+            break;
+        }
+
+        let mut block = combine_shares_with_context(&mut ctx, shares.to_vec())?;
+        carry.extend_from_slice(&block);
+        wipe_bytes(&mut block);
+        if carry.len() > HOLD_BACK_SIZE {
+            let ready = carry.len() - HOLD_BACK_SIZE;
+            out.write_all(&carry[..ready])?;
+            written += ready as u64;
+            carry.copy_within(ready.., 0);
+            carry.truncate(HOLD_BACK_SIZE);
+        }
+    }
+
+    let invalid_trailer = || {
+        SSSError::WithReason(
+            "Recreated secret has no valid length trailer, shares are not streamed shares."
+                .to_owned(),
+        )
+    };
+    let content = carry
+        .len()
+        .checked_sub(LENGTH_TRAILER_SIZE)
+        .ok_or_else(invalid_trailer)?;
+    let mut trailer = [0u8; LENGTH_TRAILER_SIZE];
+    trailer.copy_from_slice(&carry[content..]);
+    let remaining = u64::from_be_bytes(trailer)
+        .checked_sub(written)
+        .filter(|remaining| *remaining <= content as u64)
+        .ok_or_else(invalid_trailer)? as usize;
+    if carry[remaining..content].iter().any(|b| *b != 0) {
+        return Err(invalid_trailer());
+    }
+    out.write_all(&carry[..remaining])?;
+
+    Ok(())
+}
+
+/// Reads from given reader until the buffer is full or the reader is exhausted.
+/// Returns the number of bytes read.
+///
+#[inline(always)]
+fn read_block<R: Read>(reader: &mut R, buffer: &mut [u8]) -> Result<usize, SSSError> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(filled)
+}
+
+/// Writes body of every share to the corresponding writer.
+///
+#[inline(always)]
+fn write_shares<W: Write>(writers: &mut [W], shares: &[Vec<u8>]) -> Result<(), SSSError> {
+    for (writer, share) in writers.iter_mut().zip(shares.iter()) {
+        writer.write_all(&share[SHARE_HEADER_SIZE..])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shamirss::combine_shares;
+    use openssl::rand::rand_bytes;
+
+    #[test]
+    fn it_should_split_stream_to_standard_shares_of_padded_secret() -> Result<(), SSSError> {
+        let mut secret = vec![0; 10 * 1024 * 1024 + 5];
+        rand_bytes(&mut secret)?;
+        let mut writers = vec![std::io::Cursor::new(Vec::new()); 3];
+        split_reader(2, 3, std::io::Cursor::new(&secret), &mut writers)?;
+
+        let shares: Vec<Vec<u8>> = writers.into_iter().map(|w| w.into_inner()).collect();
+        let padded = combine_shares(shares[1..].to_vec())?;
+        assert_eq!(
+            padded.len(),
+            (secret.len() + LENGTH_TRAILER_SIZE).div_ceil(32) * 32
+        );
+        assert_eq!(&padded[..secret.len()], &secret[..]);
+        assert!(padded[secret.len()..padded.len() - LENGTH_TRAILER_SIZE]
+            .iter()
+            .all(|b| *b == 0));
+        assert_eq!(
+            padded[padded.len() - LENGTH_TRAILER_SIZE..],
+            (secret.len() as u64).to_be_bytes()
+        );
+
+        let mut writers = vec![Vec::new(); 2];
+        assert!(split_reader(2, 3, secret.as_slice(), &mut writers).is_err());
+
+        for (min, total) in [(1, 3), (4, 3)] {
+            let mut writers = vec![Vec::new(); total];
+            assert!(split_reader(min, total, secret.as_slice(), &mut writers).is_err());
+            assert!(writers.iter().all(|w| w.is_empty()));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_streamed_shares_byte_exact() -> Result<(), SSSError> {
+        for size in [
+            0,
+            1,
+            STREAM_BLOCK_SIZE - 8,
+            STREAM_BLOCK_SIZE,
+            3 * 1024 * 1024 + 17,
+        ] {
+            let mut secret = vec![0; size];
+            rand_bytes(&mut secret)?;
+            let mut writers = vec![Vec::new(); 5];
+            split_reader(3, 5, secret.as_slice(), &mut writers)?;
+
+            let mut readers: Vec<&[u8]> = writers[2..].iter().map(|w| w.as_slice()).collect();
+            let mut recreated = Vec::new();
+            combine_readers(&mut readers, &mut recreated)?;
+            assert_eq!(recreated, secret);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_share_readers_of_unequal_length_with_block_index() -> Result<(), SSSError> {
+        let secret = vec![3; 3 * STREAM_BLOCK_SIZE + 100];
+        let mut writers = vec![Vec::new(); 3];
+        split_reader(2, 3, secret.as_slice(), &mut writers)?;
+        let truncated = writers[1].len() - COEFFICIENTS_SIZE;
+        writers[1].truncate(truncated);
+
+        let mut readers: Vec<&[u8]> = writers.iter().map(|w| w.as_slice()).collect();
+        match combine_readers(&mut readers, Vec::new()) {
+            Err(SSSError::WithReason(reason)) => assert!(reason.contains("block 3")),
+            other => panic!("expected unequal length error, got {other:?}"),
+        }
+
+        Ok(())
+    }
+}
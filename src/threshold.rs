@@ -0,0 +1,60 @@
+use crate::{errors::SSSError, shamirss::MIN_SHARES_COUNT};
+
+/// Threshold is a validated pair of minimum shares amount and total shares amount.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Threshold {
+    min: usize,
+    total: usize,
+}
+
+impl Threshold {
+    /// Creates threshold from given minimum and total shares amount.
+    /// Minimum shall be bigger than one and not bigger than total.
+    ///
+    pub fn new(min: usize, total: usize) -> Result<Self, SSSError> {
+        if min < MIN_SHARES_COUNT {
+            return Err(SSSError::WithReason(format!(
+                "Minimum value cannot be smaller then {MIN_SHARES_COUNT}."
+            )));
+        }
+        if min > total {
+            return Err(SSSError::MinGreaterThanShares { min, shares: total });
+        }
+        Ok(Self { min, total })
+    }
+
+    /// Returns minimum shares amount needed to recreate the secret.
+    ///
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    /// Returns total shares amount to create.
+    ///
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_create_valid_thresholds() -> Result<(), SSSError> {
+        let threshold = Threshold::new(3, 5)?;
+        assert_eq!(threshold.min(), 3);
+        assert_eq!(threshold.total(), 5);
+        assert!(Threshold::new(2, 2).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_create_invalid_thresholds() {
+        assert!(Threshold::new(0, 5).is_err());
+        assert!(Threshold::new(1, 5).is_err());
+        assert!(Threshold::new(5, 3).is_err());
+    }
+}
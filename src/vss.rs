@@ -0,0 +1,219 @@
+use crate::{
+    errors::SSSError,
+    operations::{default_prime, is_proper_size, U8S_TO_BIG_INT_INITIAL},
+    shamirss::{
+        create_shares_with_polynomial, share_body, share_version, COEFFICIENTS_SIZE,
+        SHARE_FORMAT_VERSION, THRESHOLD_FORMAT_VERSION,
+    },
+};
+use openssl::bn::{BigNum, BigNumContext, BigNumContextRef};
+use std::sync::OnceLock;
+
+/// Prime modulus of the commitments group, (2^1791 + 16) * q + 1, where q is the default prime
+/// shares are calculated over. Subgroup of order q is generated by 2^((P - 1) / q).
+///
+const GROUP_PRIME: &str = "16158503035655503650357438344334975980222051334857742016065172713762327569475391070675336783658018398921191643787473368145890327088719904191516480893096983286586052236780063184869753307012137409917347357458914013186127088569309701908984493752559760690198947267381200365272197353196394935599341603218049991639570127196945604989481474910976062023179439189530160434263403825892205895931787399782665800264932751842600004476346175391640564027611074727866725901074676057019815637120213063297881609973696579523989226084588709351836802057892463388583802335392419825480992554831528156962319694630317207883223504256838779540113";
+const GROUP_BASE: u32 = 2;
+const COMMITMENT_SIZE: i32 = 256;
+
+/// Commitments holds Feldman commitments g^coefficient mod P to the coefficients of every chunk
+/// polynomial, one list per chunk, starting with the commitment to the secret chunk.
+/// Commitments can be published, but reveal g^secret, so they shall be used for random secrets
+/// such as keys only.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commitments {
+    pub chunks: Vec<Vec<Vec<u8>>>,
+}
+
+/// Creates shares from given secret together with the Feldman commitments to the polynomials,
+/// so every shareholder can verify the share lies on the committed polynomials.
+///
+/// # Argument
+///
+/// * `min`     - minimal amount of shares required to recreate the secret.
+/// * `total`   - total amount of shares.
+/// * `secret`  - secret to be shared, divisible by 32 without rest.
+///
+/// # Examples
+///
+/// ```
+///use shamirss::{combine_std, create_verifiable, verify_share};
+///
+///let secret = vec![7; 64];
+///let (shares, commitments) = create_verifiable(3, 5, &secret).unwrap();
+///assert!(verify_share(&shares[0], &commitments).unwrap());
+///assert_eq!(secret, combine_std(shares[..3].to_vec()).unwrap());
+///```
+///
+pub fn create_verifiable(
+    min: usize,
+    total: usize,
+    secret: &[u8],
+) -> Result<(Vec<Vec<u8>>, Commitments), SSSError> {
+    if !is_proper_size(secret) {
+        return Err(SSSError::SecretNotAligned {
+            len: secret.len(),
+            block: U8S_TO_BIG_INT_INITIAL,
+        });
+    }
+    create_shares_with_polynomial(min, total, secret, |ctx, polynomial| {
+        let (prime, generator) = group()?;
+        let mut commitment = BigNum::new()?;
+        let mut chunks = Vec::with_capacity(polynomial.len());
+        for coefficients in polynomial.iter() {
+            let mut chunk = Vec::with_capacity(coefficients.len());
+            for coefficient in coefficients.iter() {
+                commitment.mod_exp(generator, coefficient, prime, ctx)?;
+                chunk.push(commitment.to_vec_padded(COMMITMENT_SIZE)?);
+            }
+            chunks.push(chunk);
+        }
+        Ok(Commitments { chunks })
+    })
+}
+
+/// Verifies the share lies on the polynomials committed to by given commitments.
+/// Returns false for a share inconsistent with the commitments and error for a share or
+/// commitments that cannot be verified.
+///
+/// # Argument
+///
+/// * `share`        - share to verify.
+/// * `commitments`  - commitments created together with the shares.
+///
+pub fn verify_share(share: &[u8], commitments: &Commitments) -> Result<bool, SSSError> {
+    if !matches!(
+        share_version(share)?,
+        SHARE_FORMAT_VERSION | THRESHOLD_FORMAT_VERSION
+    ) {
+        return Err(SSSError::WithReason(format!(
+            "Only shares of format version {SHARE_FORMAT_VERSION} and {THRESHOLD_FORMAT_VERSION} can be verified."
+        )));
+    }
+    let body = share_body(share)?;
+    if body.len() / COEFFICIENTS_SIZE != commitments.chunks.len() {
+        return Err(SSSError::WithReason(format!(
+            "Share holds {} chunks, commitments are given for {} chunks.",
+            body.len() / COEFFICIENTS_SIZE,
+            commitments.chunks.len()
+        )));
+    }
+
+    let mut ctx = BigNumContext::new()?;
+    for (unit, chunk) in body
+        .chunks(COEFFICIENTS_SIZE)
+        .zip(commitments.chunks.iter())
+    {
+        let x = BigNum::from_slice(&unit[..U8S_TO_BIG_INT_INITIAL])?;
+        let y = BigNum::from_slice(&unit[U8S_TO_BIG_INT_INITIAL..])?;
+        if !verify_point(&mut ctx, &x, &y, chunk)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Checks g^y equals the product of commitments C_i^(x^i), all mod P.
+///
+#[inline(always)]
+fn verify_point(
+    ctx: &mut BigNumContextRef,
+    x: &BigNum,
+    y: &BigNum,
+    commitments: &[Vec<u8>],
+) -> Result<bool, SSSError> {
+    if commitments.len() < 2 {
+        return Err(SSSError::WithReason(
+            "Every chunk shall have at least two commitments.".to_owned(),
+        ));
+    }
+    let (prime, generator) = group()?;
+    let order = default_prime()?;
+
+    let mut expected = BigNum::new()?;
+    expected.mod_exp(generator, y, prime, ctx)?;
+
+    let mut product = BigNum::from_u32(1)?;
+    let mut power = BigNum::from_u32(1)?;
+    let mut term = BigNum::new()?;
+    let mut temp = BigNum::new()?;
+    for commitment in commitments.iter() {
+        let commitment = BigNum::from_slice(commitment)?;
+        term.mod_exp(&commitment, &power, prime, ctx)?;
+        temp.mod_mul(&product, &term, prime, ctx)?;
+        std::mem::swap(&mut product, &mut temp);
+        temp.mod_mul(&power, x, order, ctx)?;
+        std::mem::swap(&mut power, &mut temp);
+    }
+
+    Ok(expected == product)
+}
+
+/// Returns the commitments group prime and generator computed once and cached for the process
+/// lifetime.
+///
+#[inline(always)]
+fn group() -> Result<&'static (BigNum, BigNum), SSSError> {
+    static GROUP: OnceLock<(BigNum, BigNum)> = OnceLock::new();
+    if let Some(group) = GROUP.get() {
+        return Ok(group);
+    }
+    let mut ctx = BigNumContext::new()?;
+    let prime = BigNum::from_dec_str(GROUP_PRIME)?;
+    let one = BigNum::from_u32(1)?;
+    let base = BigNum::from_u32(GROUP_BASE)?;
+    let mut group_order = BigNum::new()?;
+    group_order.checked_sub(&prime, &one)?;
+    let mut exponent = BigNum::new()?;
+    exponent.checked_div(&group_order, default_prime()?, &mut ctx)?;
+    let mut generator = BigNum::new()?;
+    generator.mod_exp(&base, &exponent, &prime, &mut ctx)?;
+    Ok(GROUP.get_or_init(|| (prime, generator)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shamirss::combine_shares;
+
+    #[test]
+    fn it_should_use_generator_of_the_default_prime_order_subgroup() -> Result<(), SSSError> {
+        let mut ctx = BigNumContext::new()?;
+        let (prime, generator) = group()?;
+        assert!(prime.is_prime(64, &mut ctx)?);
+        assert_eq!(prime.num_bits(), COMMITMENT_SIZE * 8);
+        assert_ne!(*generator, BigNum::from_u32(1)?);
+
+        let mut identity = BigNum::new()?;
+        identity.mod_exp(generator, default_prime()?, prime, &mut ctx)?;
+        assert_eq!(identity, BigNum::from_u32(1)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_verify_valid_shares_and_reject_tampered_y() -> Result<(), SSSError> {
+        let secret = vec![9; 96];
+        let (shares, commitments) = create_verifiable(3, 5, &secret)?;
+        assert_eq!(commitments.chunks.len(), 3);
+        assert!(commitments.chunks.iter().all(|chunk| chunk.len() == 3));
+        for share in shares.iter() {
+            assert!(verify_share(share, &commitments)?);
+        }
+        assert_eq!(combine_shares(shares[2..].to_vec())?, secret);
+
+        let mut tampered = shares[1].clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        assert!(!verify_share(&tampered, &commitments)?);
+
+        let (other_shares, _) = create_verifiable(3, 5, &secret)?;
+        assert!(!verify_share(&other_shares[0], &commitments)?);
+        assert!(verify_share(&shares[0][..1 + COEFFICIENTS_SIZE], &commitments).is_err());
+        assert!(verify_share(&shares[0][1..], &commitments).is_err());
+
+        Ok(())
+    }
+}